@@ -0,0 +1,106 @@
+//! Minimal base64 decoder shared by the validators.
+
+/// Alphabet of a base64 encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Alphabet {
+    /// The standard alphabet with padding, from RFC 4648 section 4.
+    Standard,
+    /// The URL and filename safe alphabet without padding, from RFC 4648
+    /// section 5, as used by JWT.
+    UrlSafeNoPad,
+}
+
+impl Alphabet {
+    fn value(self, byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' if self == Alphabet::Standard => Some(62),
+            b'/' if self == Alphabet::Standard => Some(63),
+            b'-' if self == Alphabet::UrlSafeNoPad => Some(62),
+            b'_' if self == Alphabet::UrlSafeNoPad => Some(63),
+            _ => None,
+        }
+    }
+}
+
+/// Decode `input`, returning the reason of the failure if it is not valid.
+pub(crate) fn decode(input: &str, alphabet: Alphabet) -> Result<Vec<u8>, String> {
+    let data = match alphabet {
+        Alphabet::Standard => {
+            if !input.len().is_multiple_of(4) {
+                return Err(format!("length {} is not a multiple of 4", input.len()));
+            }
+            let data = input.trim_end_matches('=');
+            if input.len() - data.len() > 2 {
+                return Err(String::from("too much padding"));
+            }
+            data
+        }
+        Alphabet::UrlSafeNoPad => {
+            if input.len() % 4 == 1 {
+                return Err(format!("invalid length {}", input.len()));
+            }
+            input
+        }
+    };
+
+    let mut output = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for (position, byte) in data.bytes().enumerate() {
+        let value = alphabet.value(byte).ok_or_else(|| {
+            let character = data[position..].chars().next().unwrap_or_default();
+            format!("invalid character '{character}' at position {position}")
+        })?;
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push(u8::try_from(buffer >> bits).unwrap());
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    if buffer != 0 {
+        return Err(String::from("non-zero trailing bits"));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, Alphabet};
+
+    #[test]
+    fn standard() {
+        assert_eq!(Ok(b"".to_vec()), decode("", Alphabet::Standard));
+        assert_eq!(Ok(b"f".to_vec()), decode("Zg==", Alphabet::Standard));
+        assert_eq!(Ok(b"fo".to_vec()), decode("Zm8=", Alphabet::Standard));
+        assert_eq!(
+            Ok(b"foobar".to_vec()),
+            decode("Zm9vYmFy", Alphabet::Standard)
+        );
+        assert_eq!(Ok(vec![0xfb, 0xff]), decode("+/8=", Alphabet::Standard));
+    }
+
+    #[test]
+    fn standard_invalid() {
+        assert!(decode("Zg", Alphabet::Standard).is_err());
+        assert!(decode("Z===", Alphabet::Standard).is_err());
+        assert!(decode("Zh==", Alphabet::Standard).is_err());
+        assert_eq!(
+            Err(String::from("invalid character '-' at position 1")),
+            decode("Z-==", Alphabet::Standard)
+        );
+    }
+
+    #[test]
+    fn url_safe() {
+        assert_eq!(Ok(b"f".to_vec()), decode("Zg", Alphabet::UrlSafeNoPad));
+        assert_eq!(Ok(vec![0xfb, 0xff]), decode("-_8", Alphabet::UrlSafeNoPad));
+        assert!(decode("Zg==", Alphabet::UrlSafeNoPad).is_err());
+        assert!(decode("Z", Alphabet::UrlSafeNoPad).is_err());
+    }
+}
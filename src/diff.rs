@@ -0,0 +1,234 @@
+//! Character level diff of strings displayed in the diagnostics.
+
+/// Strings with fewer characters are fully readable in the error message and
+/// don't get a diff.
+pub(crate) const MIN_DIFF_LEN: usize = 32;
+
+/// Number of unchanged characters displayed around a change.
+const CONTEXT: usize = 10;
+
+/// Unchanged runs shorter than this between two changes are merged into the
+/// changes, to avoid diffs aligning unrelated words on a single character.
+const MIN_EQUAL_RUN: usize = 3;
+
+/// Above this size of the comparison table, the changed part is displayed as a
+/// single replacement.
+const MAX_TABLE_SIZE: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Equal,
+    Removed,
+    Added,
+}
+
+/// Describe the differences between `expected` and `actual` inline: the
+/// removed characters as `[-...-]` and the added ones as `{+...+}`.
+///
+/// Long runs of unchanged characters are elided with `…`.
+pub(crate) fn inline_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<char> = expected.chars().collect();
+    let actual: Vec<char> = actual.chars().collect();
+
+    let prefix = expected
+        .iter()
+        .zip(&actual)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut changes: Vec<(Change, char)> = expected[..prefix]
+        .iter()
+        .map(|&c| (Change::Equal, c))
+        .collect();
+    changes.extend(diff_chars(
+        &expected[prefix..expected.len() - suffix],
+        &actual[prefix..actual.len() - suffix],
+    ));
+    changes.extend(
+        expected[expected.len() - suffix..]
+            .iter()
+            .map(|&c| (Change::Equal, c)),
+    );
+
+    render(&changes)
+}
+
+/// Diff the characters using their longest common subsequence.
+fn diff_chars(expected: &[char], actual: &[char]) -> Vec<(Change, char)> {
+    if expected.len() * actual.len() > MAX_TABLE_SIZE {
+        return expected
+            .iter()
+            .map(|&c| (Change::Removed, c))
+            .chain(actual.iter().map(|&c| (Change::Added, c)))
+            .collect();
+    }
+
+    // lengths[i][j] is the length of the longest common subsequence of
+    // expected[i..] and actual[j..].
+    let mut lengths = vec![vec![0_usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::with_capacity(expected.len() + actual.len());
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            changes.push((Change::Equal, expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len()
+            || (i < expected.len() && lengths[i + 1][j] >= lengths[i][j + 1])
+        {
+            changes.push((Change::Removed, expected[i]));
+            i += 1;
+        } else {
+            changes.push((Change::Added, actual[j]));
+            j += 1;
+        }
+    }
+    merge_short_runs(&changes)
+}
+
+/// Merge the short unchanged runs into the surrounding changes, with the
+/// removed characters of each change before the added ones.
+///
+/// The first and last characters of `changes` must be changed.
+fn merge_short_runs(changes: &[(Change, char)]) -> Vec<(Change, char)> {
+    let mut merged = Vec::with_capacity(changes.len());
+    let mut removed = vec![];
+    let mut added = vec![];
+    for run in changes.chunk_by(|a, b| a.0 == b.0) {
+        match run[0].0 {
+            Change::Equal if run.len() >= MIN_EQUAL_RUN => {
+                merged.append(&mut removed);
+                merged.append(&mut added);
+                merged.extend_from_slice(run);
+            }
+            Change::Equal => {
+                removed.extend(run.iter().map(|&(_, c)| (Change::Removed, c)));
+                added.extend(run.iter().map(|&(_, c)| (Change::Added, c)));
+            }
+            Change::Removed => removed.extend_from_slice(run),
+            Change::Added => added.extend_from_slice(run),
+        }
+    }
+    merged.append(&mut removed);
+    merged.append(&mut added);
+    merged
+}
+
+fn render(changes: &[(Change, char)]) -> String {
+    let mut output = String::new();
+    let mut start = 0;
+    while start < changes.len() {
+        let change = changes[start].0;
+        let end = changes[start..]
+            .iter()
+            .position(|(other, _)| *other != change)
+            .map_or(changes.len(), |length| start + length);
+        let text: Vec<char> = changes[start..end].iter().map(|(_, c)| *c).collect();
+
+        match change {
+            Change::Equal => {
+                let keep_start = if start == 0 { 0 } else { CONTEXT };
+                let keep_end = if end == changes.len() { 0 } else { CONTEXT };
+                let unchanged = start == 0 && end == changes.len();
+                if !unchanged && text.len() > keep_start + keep_end {
+                    push_chars(&mut output, &text[..keep_start]);
+                    output.push('…');
+                    push_chars(&mut output, &text[text.len() - keep_end..]);
+                } else {
+                    push_chars(&mut output, &text);
+                }
+            }
+            Change::Removed => {
+                output.push_str("[-");
+                push_chars(&mut output, &text);
+                output.push_str("-]");
+            }
+            Change::Added => {
+                output.push_str("{+");
+                push_chars(&mut output, &text);
+                output.push_str("+}");
+            }
+        }
+        start = end;
+    }
+    output
+}
+
+/// Push the characters with the control characters escaped, to keep the diff
+/// on a single line.
+fn push_chars(output: &mut String, chars: &[char]) {
+    for c in chars {
+        if c.is_control() {
+            output.extend(c.escape_default());
+        } else {
+            output.push(*c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inline_diff;
+
+    #[test]
+    fn replacement() {
+        assert_eq!(
+            "the quick [-brown-]{+red+} fox",
+            inline_diff("the quick brown fox", "the quick red fox")
+        );
+    }
+
+    #[test]
+    fn insertion_and_removal() {
+        assert_eq!("ab{+X+}cd", inline_diff("abcd", "abXcd"));
+        assert_eq!("ab[-cd-]", inline_diff("abcd", "ab"));
+        assert_eq!("{+abc+}", inline_diff("", "abc"));
+        assert_eq!("abc", inline_diff("abc", "abc"));
+    }
+
+    #[test]
+    fn short_unchanged_runs() {
+        assert_eq!("a[-cd-]{+bc+}", inline_diff("acd", "abc"));
+        assert_eq!("[-abc-]{+xbcy+}", inline_diff("abc", "xbcy"));
+        assert_eq!("[-x-]{+y+}abc[-x-]{+y+}", inline_diff("xabcx", "yabcy"));
+    }
+
+    #[test]
+    fn elided_context() {
+        assert_eq!(
+            "…1234567890[-a-]{+b+}1234567890…",
+            inline_diff(
+                "xxxxxxxxxx1234567890a1234567890yyyyyyyyyy",
+                "xxxxxxxxxx1234567890b1234567890yyyyyyyyyy"
+            )
+        );
+        assert_eq!(
+            "[-a-]{+b+}1234567890…1234567890[-c-]{+d+}",
+            inline_diff(
+                "a1234567890xxxxxxxxxx1234567890c",
+                "b1234567890xxxxxxxxxx1234567890d"
+            )
+        );
+    }
+
+    #[test]
+    fn control_characters() {
+        assert_eq!("a[-\\n-]{+ +}b", inline_diff("a\nb", "a b"));
+    }
+}
@@ -0,0 +1,722 @@
+use core::fmt;
+use std::fmt::Write as _;
+
+use crate::diff::{self, MIN_DIFF_LEN};
+use crate::macros_utils::{self, Anchor};
+use crate::validators::{StringComparison, Tolerance};
+use crate::{JsonPointer, Value};
+
+/// Validation error
+///
+/// It points to the invalid value with both a reference into the JSON input
+/// and the [`JsonPointer`] of this value.
+#[derive(Debug, PartialEq)]
+pub struct Error<'a> {
+    location: &'a Value,
+    path: JsonPointer,
+    kind: ErrorKind,
+    causes: Vec<Error<'a>>,
+}
+
+/// Kind of validation error
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The value is not of the expected type.
+    InvalidType(JsonType),
+    /// The value doesn't match the expectation described by the string.
+    InvalidValue(String),
+    /// The value is not equal to the expected one.
+    NotEqual { expected: Value, actual: Value },
+    /// The string is not equal to the expected one according to `comparison`.
+    StringNotEqual {
+        expected: String,
+        comparison: StringComparison,
+    },
+    /// The length of the value doesn't match the expectation described by
+    /// `expected`.
+    InvalidLength { expected: String, actual: usize },
+    /// The JSON document embedded in the value doesn't match, see `errors` for
+    /// the errors inside `document`.
+    InvalidEmbeddedJson {
+        document: Value,
+        errors: Vec<OwnedError>,
+    },
+    /// The number is not within the tolerance of the expected one.
+    NotApproxEqual { expected: f64, tolerance: Tolerance },
+    /// The object doesn't have the given key.
+    MissingObjectKey(String),
+    /// The object has the given key while it was not expected.
+    UnexpectedObjectKey(String),
+    /// The key doesn't match the expectation described by `expected`.
+    InvalidObjectKey { key: String, expected: String },
+    /// The validator at the given index of an unordered array validator
+    /// doesn't match any element. The second field lists the elements it
+    /// matches which were assigned to other validators.
+    UnmatchedValidator(usize, Vec<usize>),
+    /// None of the alternatives matched, see [`Error::causes`] for the error of
+    /// each alternative.
+    NoneMatched(usize),
+    /// More than one alternative matched while only one was expected.
+    MultipleMatched(Vec<usize>),
+    /// The number of array elements matching a validator is not the expected
+    /// one. `matching` lists the indexes of the matching elements.
+    UnexpectedMatchCount {
+        expected: String,
+        matching: Vec<usize>,
+    },
+    /// The value matched a validator it was expected not to match.
+    UnexpectedMatch,
+    /// Error raised by a custom validator.
+    Custom {
+        message: String,
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+}
+
+impl PartialEq for ErrorKind {
+    fn eq(&self, other: &ErrorKind) -> bool {
+        match (self, other) {
+            (ErrorKind::InvalidType(a), ErrorKind::InvalidType(b)) => a == b,
+            (ErrorKind::InvalidValue(a), ErrorKind::InvalidValue(b))
+            | (ErrorKind::MissingObjectKey(a), ErrorKind::MissingObjectKey(b))
+            | (ErrorKind::UnexpectedObjectKey(a), ErrorKind::UnexpectedObjectKey(b)) => a == b,
+            (
+                ErrorKind::InvalidObjectKey { key, expected },
+                ErrorKind::InvalidObjectKey {
+                    key: other_key,
+                    expected: other_expected,
+                },
+            ) => key == other_key && expected == other_expected,
+            (
+                ErrorKind::NotEqual { expected, actual },
+                ErrorKind::NotEqual {
+                    expected: other_expected,
+                    actual: other_actual,
+                },
+            ) => expected == other_expected && actual == other_actual,
+            (
+                ErrorKind::StringNotEqual {
+                    expected,
+                    comparison,
+                },
+                ErrorKind::StringNotEqual {
+                    expected: other_expected,
+                    comparison: other_comparison,
+                },
+            ) => expected == other_expected && comparison == other_comparison,
+            (
+                ErrorKind::InvalidLength { expected, actual },
+                ErrorKind::InvalidLength {
+                    expected: other_expected,
+                    actual: other_actual,
+                },
+            ) => expected == other_expected && actual == other_actual,
+            (
+                ErrorKind::InvalidEmbeddedJson { document, errors },
+                ErrorKind::InvalidEmbeddedJson {
+                    document: other_document,
+                    errors: other_errors,
+                },
+            ) => document == other_document && errors == other_errors,
+            (
+                ErrorKind::NotApproxEqual {
+                    expected,
+                    tolerance,
+                },
+                ErrorKind::NotApproxEqual {
+                    expected: other_expected,
+                    tolerance: other_tolerance,
+                },
+            ) => expected.to_bits() == other_expected.to_bits() && tolerance == other_tolerance,
+            (
+                ErrorKind::UnmatchedValidator(a, a_list),
+                ErrorKind::UnmatchedValidator(b, b_list),
+            ) => a == b && a_list == b_list,
+            (ErrorKind::NoneMatched(a), ErrorKind::NoneMatched(b)) => a == b,
+            (ErrorKind::MultipleMatched(a), ErrorKind::MultipleMatched(b)) => a == b,
+            (
+                ErrorKind::UnexpectedMatchCount { expected, matching },
+                ErrorKind::UnexpectedMatchCount {
+                    expected: other_expected,
+                    matching: other_matching,
+                },
+            ) => expected == other_expected && matching == other_matching,
+            (ErrorKind::UnexpectedMatch, ErrorKind::UnexpectedMatch) => true,
+            // The sources can't be compared, compare their messages instead.
+            (
+                ErrorKind::Custom { message, source },
+                ErrorKind::Custom {
+                    message: other_message,
+                    source: other_source,
+                },
+            ) => {
+                message == other_message
+                    && source.as_ref().map(ToString::to_string)
+                        == other_source.as_ref().map(ToString::to_string)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Type of a JSON value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonType {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    /// The type of `value`.
+    #[must_use]
+    pub fn of(value: &Value) -> JsonType {
+        match value {
+            Value::Null => JsonType::Null,
+            Value::Bool(_) => JsonType::Bool,
+            Value::Number(_) => JsonType::Number,
+            Value::String(_) => JsonType::String,
+            Value::Array(_) => JsonType::Array,
+            Value::Object(_) => JsonType::Object,
+        }
+    }
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            JsonType::Null => "null",
+            JsonType::Bool => "bool",
+            JsonType::Number => "number",
+            JsonType::String => "string",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        })
+    }
+}
+
+impl<'a> Error<'a> {
+    /// Create a new error for the invalid value `location`.
+    ///
+    /// The path of the error is relative to `location` and is completed by the
+    /// parent validators with [`Error::at_key`] and [`Error::at_index`].
+    #[must_use]
+    pub fn new(location: &'a Value, kind: ErrorKind) -> Error<'a> {
+        Error {
+            location,
+            path: JsonPointer::root(),
+            kind,
+            causes: vec![],
+        }
+    }
+
+    /// Attach the errors which caused this error.
+    ///
+    /// It is used by validators combining other validators (like
+    /// [`Validator::or`](crate::Validator::or)) to explain why each of them failed.
+    #[must_use]
+    pub fn with_causes(mut self, causes: Vec<Error<'a>>) -> Error<'a> {
+        self.causes = causes;
+        self
+    }
+
+    /// The invalid value.
+    #[must_use]
+    pub fn location(&self) -> &'a Value {
+        self.location
+    }
+
+    /// The JSON pointer of the invalid value.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    /// The kind of validation error.
+    #[must_use]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The errors which caused this error.
+    #[must_use]
+    pub fn causes(&self) -> &[Error<'a>] {
+        &self.causes
+    }
+
+    /// The error message, without the path.
+    #[must_use]
+    pub fn message(&self) -> String {
+        match &self.kind {
+            ErrorKind::InvalidType(expected) => format!(
+                "Invalid type. Expected {expected} but got {}.",
+                JsonType::of(self.location)
+            ),
+            ErrorKind::InvalidValue(s) => {
+                format!("Invalid value. Expected {s} but got {}.", self.location)
+            }
+            ErrorKind::NotEqual { expected, actual } => {
+                format!("Invalid value. Expected {expected} but got {actual}.")
+            }
+            ErrorKind::StringNotEqual {
+                expected,
+                comparison,
+            } => format!(
+                "Invalid value. Expected {} {comparison} but got {}.",
+                Value::from(expected.as_str()),
+                self.location
+            ),
+            ErrorKind::InvalidLength { expected, actual } => {
+                format!("Invalid length. Expected {expected} but got {actual}.")
+            }
+            ErrorKind::InvalidEmbeddedJson { errors, .. } => {
+                let errors: Vec<_> = errors
+                    .iter()
+                    .map(|error| format!("#{}: {}", error.path(), error.message()))
+                    .collect();
+                format!("Invalid embedded JSON. {}", errors.join(" "))
+            }
+            ErrorKind::NotApproxEqual {
+                expected,
+                tolerance,
+            } => format!(
+                "Invalid value. Expected {expected} {tolerance} but got {}. The difference is {}.",
+                self.location,
+                tolerance.describe_difference(*expected, self.location.as_f64().unwrap_or(f64::NAN))
+            ),
+            ErrorKind::MissingObjectKey(s) => format!("Missing key '{s}' in object"),
+            ErrorKind::UnexpectedObjectKey(s) => format!("Key '{s}' is not expected in object"),
+            ErrorKind::InvalidObjectKey { key, expected } => {
+                format!("Invalid key '{key}'. Expected {expected}.")
+            }
+            ErrorKind::UnmatchedValidator(s, candidates) if candidates.is_empty() => {
+                format!("No match for expected array element {s}")
+            }
+            ErrorKind::UnmatchedValidator(s, candidates) => match candidates.as_slice() {
+                [index] => format!(
+                    "No match for expected array element {s}. Candidate element {index} is matched by another expected element"
+                ),
+                indexes => format!(
+                    "No match for expected array element {s}. Candidate elements {} are matched by other expected elements",
+                    join(indexes)
+                ),
+            },
+            ErrorKind::NoneMatched(n) => format!("None of the {n} alternatives matched"),
+            ErrorKind::MultipleMatched(indexes) => format!(
+                "Expected exactly one alternative to match but alternatives {} matched",
+                join(indexes)
+            ),
+            ErrorKind::UnexpectedMatchCount { expected, matching } => {
+                let matched = match matching.as_slice() {
+                    [] => String::from("none"),
+                    [index] => format!("element {index}"),
+                    indexes => format!("elements {}", join(indexes)),
+                };
+                format!(
+                    "Invalid number of matches. Expected {expected} to match but {matched} matched."
+                )
+            }
+            ErrorKind::UnexpectedMatch => format!(
+                "Invalid value. Expected a value not matching the validator but got {}.",
+                self.location
+            ),
+            ErrorKind::Custom { message, .. } => message.clone(),
+        }
+    }
+
+    /// Prefix the error path with an object key.
+    ///
+    /// Validators validating the values of an object should call it on the
+    /// errors returned by their inner validators.
+    #[must_use]
+    pub fn at_key(mut self, key: &str) -> Error<'a> {
+        self.path.push_front(key.to_string());
+        self.causes = self.causes.into_iter().map(|c| c.at_key(key)).collect();
+        self
+    }
+
+    /// Prefix the error path with an array index.
+    ///
+    /// Validators validating the elements of an array should call it on the
+    /// errors returned by their inner validators.
+    #[must_use]
+    pub fn at_index(mut self, index: usize) -> Error<'a> {
+        self.path.push_front(index.to_string());
+        self.causes = self.causes.into_iter().map(|c| c.at_index(index)).collect();
+        self
+    }
+
+    /// The notes displayed below the diagnostic of the error.
+    pub(crate) fn notes(&self) -> Vec<String> {
+        notes(self)
+    }
+}
+
+impl ErrorKind {
+    /// The part of the invalid value the diagnostic label points to.
+    pub(crate) fn anchor(&self) -> Anchor {
+        match self {
+            ErrorKind::UnexpectedObjectKey(_) | ErrorKind::InvalidObjectKey { .. } => Anchor::Key,
+            ErrorKind::MissingObjectKey(_) => Anchor::ObjectEnd,
+            _ => Anchor::Value,
+        }
+    }
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ErrorKind::Custom {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for Error<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.kind.source()
+    }
+}
+
+impl fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            f.write_str(&self.message())
+        } else {
+            write!(f, "{}: {}", self.path, self.message())
+        }
+    }
+}
+
+/// Owned counterpart of [Error].
+///
+/// It doesn't borrow the JSON input, so it can be returned from a function
+/// owning the JSON, stored or sent across threads.
+///
+/// ```
+/// use assert_json::{validators, OwnedError, Validator};
+///
+/// fn validate(json: &str) -> Result<(), OwnedError> {
+///     let value = serde_json::from_str(json).unwrap();
+///     validators::eq(5).validate(&value).map_err(OwnedError::from)
+/// }
+///
+/// let error = validate("6").unwrap_err();
+/// assert_eq!("Invalid value. Expected 5 but got 6.", error.message());
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct OwnedError {
+    value: Value,
+    path: JsonPointer,
+    kind: ErrorKind,
+    message: String,
+    causes: Vec<OwnedError>,
+}
+
+impl OwnedError {
+    /// A copy of the invalid value.
+    #[must_use]
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// The JSON pointer of the invalid value.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    /// The kind of validation error.
+    #[must_use]
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The error message, without the path.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The errors which caused this error.
+    #[must_use]
+    pub fn causes(&self) -> &[OwnedError] {
+        &self.causes
+    }
+
+    /// Render the error with the invalid value as the only source.
+    #[must_use]
+    pub fn render(&self) -> String {
+        macros_utils::format_labels(
+            &self.value,
+            None,
+            &[(&self.value, self.kind.anchor(), self.to_string())],
+            notes(self),
+        )
+    }
+
+    /// Render the error the same way as [`assert_json`!](crate::assert_json) with
+    /// `json`, the JSON input the error comes from.
+    ///
+    /// Fallback to [`OwnedError::render`] if the path of the error doesn't
+    /// exist in `json`.
+    #[must_use]
+    pub fn render_in(&self, json: &Value) -> String {
+        match json.pointer(&self.path.to_string()) {
+            Some(location) => macros_utils::format_labels(
+                json,
+                None,
+                &[(location, self.kind.anchor(), self.to_string())],
+                notes(self),
+            ),
+            None => self.render(),
+        }
+    }
+}
+
+impl From<Error<'_>> for OwnedError {
+    fn from(error: Error<'_>) -> OwnedError {
+        let message = error.message();
+        OwnedError {
+            value: error.location.clone(),
+            path: error.path,
+            kind: error.kind,
+            message,
+            causes: error.causes.into_iter().map(OwnedError::from).collect(),
+        }
+    }
+}
+
+impl std::error::Error for OwnedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.kind.source()
+    }
+}
+
+impl fmt::Display for OwnedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_root() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+fn join(indexes: &[usize]) -> String {
+    indexes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Common interface of [Error] and [`OwnedError`] to render their notes.
+trait Noted: fmt::Display + Sized {
+    fn value(&self) -> &Value;
+    fn path(&self) -> &JsonPointer;
+    fn kind(&self) -> &ErrorKind;
+    fn causes(&self) -> &[Self];
+}
+
+impl Noted for Error<'_> {
+    fn value(&self) -> &Value {
+        self.location
+    }
+
+    fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    fn causes(&self) -> &[Self] {
+        &self.causes
+    }
+}
+
+impl Noted for OwnedError {
+    fn value(&self) -> &Value {
+        &self.value
+    }
+
+    fn path(&self) -> &JsonPointer {
+        &self.path
+    }
+
+    fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    fn causes(&self) -> &[Self] {
+        &self.causes
+    }
+}
+
+fn notes<E: Noted>(error: &E) -> Vec<String> {
+    keys_note(error)
+        .into_iter()
+        .chain(diff_note(error))
+        .chain(embedded_note(error))
+        .chain(causes_note(error))
+        .collect()
+}
+
+/// List the keys present in the object missing a key.
+fn keys_note<E: Noted>(error: &E) -> Option<String> {
+    if !matches!(error.kind(), ErrorKind::MissingObjectKey(_)) {
+        return None;
+    }
+    let object = error.value().as_object()?;
+    let target = if error.path().is_root() {
+        String::from("the object")
+    } else {
+        error.path().to_string()
+    };
+
+    if object.is_empty() {
+        Some(format!("{target} has no keys"))
+    } else {
+        let keys: Vec<_> = object.keys().map(|key| format!("'{key}'")).collect();
+        Some(format!("keys present in {target}: {}", keys.join(", ")))
+    }
+}
+
+/// Show the differences between long strings which are not equal.
+fn diff_note<E: Noted>(error: &E) -> Option<String> {
+    let ErrorKind::StringNotEqual {
+        expected,
+        comparison,
+    } = error.kind()
+    else {
+        return None;
+    };
+    let actual = error.value().as_str()?;
+    if expected.chars().count() < MIN_DIFF_LEN && actual.chars().count() < MIN_DIFF_LEN {
+        return None;
+    }
+
+    Some(format!(
+        "difference {comparison} ([-expected-] {{+actual+}}): {}",
+        diff::inline_diff(
+            &comparison.normalize(expected),
+            &comparison.normalize(actual)
+        )
+    ))
+}
+
+/// Render the errors inside an embedded JSON document as a nested diagnostic,
+/// with paths like `/payload#/id`.
+fn embedded_note<E: Noted>(error: &E) -> Option<String> {
+    let ErrorKind::InvalidEmbeddedJson { document, errors } = error.kind() else {
+        return None;
+    };
+
+    let labels: Vec<_> = errors
+        .iter()
+        .map(|inner| {
+            let location = document
+                .pointer(&inner.path().to_string())
+                .unwrap_or(document);
+            let message = format!("{}#{}: {}", error.path(), inner.path(), inner.message());
+            (location, inner.kind().anchor(), message)
+        })
+        .collect();
+    let notes = errors.iter().flat_map(notes).collect();
+    // The value is the source of the document when it is the JSON text.
+    let source = error.value().as_str();
+    let diagnostic = macros_utils::format_labels(document, source, &labels, notes);
+    Some(format!(
+        "embedded JSON document:\n{}",
+        diagnostic.trim_end()
+    ))
+}
+
+/// Describe the causes of an error as a nested list.
+fn causes_note<E: Noted>(error: &E) -> Option<String> {
+    fn write_causes<E: Noted>(note: &mut String, causes: &[E], depth: usize) {
+        for cause in causes {
+            write!(note, "\n{}- {cause}", "  ".repeat(depth)).unwrap();
+            write_causes(note, cause.causes(), depth + 1);
+        }
+    }
+
+    if error.causes().is_empty() {
+        return None;
+    }
+    let mut note = format!("{error}:");
+    write_causes(&mut note, error.causes(), 1);
+    Some(note)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonType, OwnedError};
+    use crate::{macros_utils, validators, Error, ErrorKind, Validator};
+
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn owned_error_is_send_sync() {
+        assert_send_sync::<OwnedError>();
+    }
+
+    #[test]
+    fn owned_error_from_error() {
+        let validator = validators::array(vec![Box::new(validators::null())]);
+        let value = serde_json::json!([5]);
+        let error = validator.validate(&value).unwrap_err();
+        let message = error.to_string();
+
+        let owned_error = OwnedError::from(error);
+        assert_eq!(&serde_json::json!(5), owned_error.value());
+        assert_eq!("/0", owned_error.path().to_string());
+        assert_eq!(message, owned_error.to_string());
+    }
+
+    #[test]
+    fn custom_error_source() {
+        let source = "x".parse::<u8>().unwrap_err();
+        let value = serde_json::json!("x");
+        let error = Error::new(
+            &value,
+            ErrorKind::Custom {
+                message: String::from("Expected a number"),
+                source: Some(Box::new(source.clone())),
+            },
+        );
+
+        assert_eq!("Expected a number", error.to_string());
+        assert_eq!(
+            source.to_string(),
+            std::error::Error::source(&error).unwrap().to_string()
+        );
+        let owned_error = OwnedError::from(error);
+        assert!(std::error::Error::source(&owned_error).is_some());
+    }
+
+    #[test]
+    fn json_type() {
+        assert_eq!(JsonType::Object, JsonType::of(&serde_json::json!({})));
+        assert_eq!("number", JsonType::of(&serde_json::json!(1.5)).to_string());
+    }
+
+    #[test]
+    fn owned_error_render_in() {
+        let validator = validators::array(vec![Box::new(validators::null())]);
+        let value = serde_json::json!([5]);
+        let error = validator.validate(&value).unwrap_err();
+        let expected = macros_utils::format_error(&value, &error);
+
+        let owned_error = OwnedError::from(error);
+        assert_eq!(expected, owned_error.render_in(&value));
+        assert!(owned_error.render().contains("/0: Invalid type"));
+    }
+}
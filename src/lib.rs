@@ -8,7 +8,6 @@
 //!
 //! ```
 //! # use assert_json::assert_json;
-//! # use assert_json::validators;
 //! #
 //! #[test]
 //! fn test_json_ok() {
@@ -27,7 +26,7 @@
 //!     assert_json!(json, {
 //!             "status": "success",
 //!             "result": {
-//!                 "age": validators::u64(|&v| if v >= 18 { Ok(())} else { Err(String::from("age should be greater or equal than 18")) }),
+//!                 "age": 18..=99,
 //!                 "name": name,
 //!             }
 //!         }
@@ -40,65 +39,13 @@ use core::fmt;
 /// A JSON-value. Used by the [Validator] trait.
 pub type Value = serde_json::Value;
 
-fn get_value_type_id(val: &Value) -> &'static str {
-    match val {
-        serde_json::Value::Null => "null",
-        serde_json::Value::Bool(_) => "bool",
-        serde_json::Value::Number(_) => "number",
-        serde_json::Value::String(_) => "string",
-        serde_json::Value::Array(_) => "array",
-        serde_json::Value::Object(_) => "object",
-    }
-}
-
-/// Validation error
-#[derive(Debug, PartialEq)]
-pub enum Error<'a> {
-    InvalidType(&'a Value, String),
-    InvalidValue(&'a Value, String),
-    MissingObjectKey(&'a Value, String),
-    UnexpectedObjectKey(&'a Value, String),
-    UnmatchedValidator(&'a Value, usize),
-}
-
-impl<'a> std::error::Error for Error<'a> {}
-
-impl<'a> fmt::Display for Error<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::InvalidType(v, s) => write!(
-                f,
-                "Invalid type. Expected {} but got {}.",
-                s,
-                get_value_type_id(v)
-            ),
-            Self::InvalidValue(v, s) => write!(f, "Invalid value. Expected {s} but got {v}."),
-            Self::MissingObjectKey(_v, s) => write!(f, "Missing key '{s}' in object"),
-            Self::UnexpectedObjectKey(_v, s) => write!(f, "Key '{s}' is not expected in object"),
-            Self::UnmatchedValidator(_v, s) => write!(f, "No match for expected array element {s}"),
-        }
-    }
-}
-
-impl<'a> Error<'a> {
-    fn location(&self) -> &'a Value {
-        match self {
-            Error::InvalidType(loc, _)
-            | Error::InvalidValue(loc, _)
-            | Error::MissingObjectKey(loc, _)
-            | Error::UnexpectedObjectKey(loc, _)
-            | Error::UnmatchedValidator(loc, _) => loc,
-        }
-    }
-}
-
 /// Abstract the validation action for [`assert_json`!] macro.
 ///
 /// Any custom validation rule can be easily use in the macro
 /// by implementing the [`Validator::validate`] method.
 ///
 /// ```
-/// use assert_json::{assert_json, Error, Validator, Value};
+/// use assert_json::{assert_json, Error, ErrorKind, JsonType, Validator, Value};
 ///
 /// fn optional_string(expected: Option<String>) -> impl Validator {
 ///     OptionalStringValidator { expected }
@@ -115,16 +62,19 @@ impl<'a> Error<'a> {
 ///         if let Some(expected_str) = &self.expected {
 ///             let string_value = value
 ///                 .as_str()
-///                 .ok_or_else(|| Error::InvalidType(value, String::from("string")))?;
+///                 .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::String)))?;
 ///
 ///             if expected_str == string_value {
 ///                 Ok(())
 ///             } else {
-///                 Err(Error::InvalidValue(value, expected_str.clone()))
+///                 Err(Error::new(value, ErrorKind::NotEqual {
+///                     expected: Value::from(expected_str.as_str()),
+///                     actual: value.clone(),
+///                 }))
 ///             }
 ///         } else {
 ///             value.as_null()
-///                 .ok_or_else(|| Error::InvalidType(value, String::from("null")))
+///                 .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Null)))
 ///         }
 ///     }
 /// }
@@ -143,6 +93,16 @@ impl<'a> Error<'a> {
 pub trait Validator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>>;
 
+    /// Validate the value and report every validation error instead of stopping
+    /// at the first one.
+    ///
+    /// The default implementation returns the error of [`Validator::validate`].
+    /// Validators containing other validators (objects, arrays, ...) override it
+    /// to collect the errors of each inner validator.
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        self.validate(value).map_err(|error| vec![error])
+    }
+
     fn and<T>(self, validator: T) -> And<Self, T>
     where
         Self: Sized,
@@ -153,6 +113,28 @@ pub trait Validator {
             second: validator,
         }
     }
+
+    /// Match if either this validator or `validator` matches.
+    ///
+    /// If both fail, the error of each of them is reported as a cause.
+    fn or<T>(self, validator: T) -> Or<Self, T>
+    where
+        Self: Sized,
+        T: Validator,
+    {
+        Or {
+            first: self,
+            second: validator,
+        }
+    }
+
+    /// Match if this validator doesn't match.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not { validator: self }
+    }
 }
 
 #[doc(hidden)]
@@ -169,6 +151,183 @@ where
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
         self.first.validate(value).and(self.second.validate(value))
     }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        collect_errors([
+            self.first.validate_all(value),
+            self.second.validate_all(value),
+        ])
+    }
+}
+
+#[doc(hidden)]
+pub struct Or<T, U> {
+    first: T,
+    second: U,
+}
+
+impl<T, U> Validator for Or<T, U>
+where
+    T: Validator,
+    U: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let Err(first_error) = self.first.validate(value) else {
+            return Ok(());
+        };
+        let Err(second_error) = self.second.validate(value) else {
+            return Ok(());
+        };
+
+        Err(Error::new(value, ErrorKind::NoneMatched(2))
+            .with_causes(vec![first_error, second_error]))
+    }
+}
+
+#[doc(hidden)]
+pub struct Not<T> {
+    validator: T,
+}
+
+impl<T> Validator for Not<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        match self.validator.validate(value) {
+            Ok(()) => Err(Error::new(value, ErrorKind::UnexpectedMatch)),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Merge the errors of multiple [`Validator::validate_all`] results.
+fn collect_errors<'a, I>(results: I) -> Result<(), Vec<Error<'a>>>
+where
+    I: IntoIterator<Item = Result<(), Vec<Error<'a>>>>,
+{
+    let errors: Vec<Error<'a>> = results
+        .into_iter()
+        .filter_map(Result::err)
+        .flatten()
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+impl<T> Validator for Box<T>
+where
+    T: Validator + ?Sized,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        (**self).validate(value)
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        (**self).validate_all(value)
+    }
+}
+
+/// Error returned by [`check_json`!] and [`check_json()`].
+///
+/// Contrary to [Error], it doesn't borrow the JSON input so it can be returned
+/// from a function or propagated with `?`. Its [`Display`](fmt::Display) and
+/// [`Debug`] implementations output the same diagnostic as [`assert_json`!].
+pub struct CheckError {
+    errors: Vec<OwnedError>,
+    input_error: Option<serde_json::Error>,
+    report: String,
+}
+
+impl CheckError {
+    fn new(
+        json: &Value,
+        source: Option<&str>,
+        errors: Vec<Error<'_>>,
+        input_notes: Vec<String>,
+    ) -> CheckError {
+        let report = macros_utils::format_errors_in_source(json, source, &errors, input_notes);
+        CheckError {
+            errors: errors.into_iter().map(OwnedError::from).collect(),
+            input_error: None,
+            report,
+        }
+    }
+
+    fn invalid_input(source: Option<&str>, error: serde_json::Error) -> CheckError {
+        CheckError {
+            errors: vec![],
+            report: macros_utils::format_input_error(source, &error),
+            input_error: Some(error),
+        }
+    }
+
+    /// The validation errors.
+    ///
+    /// It is empty if the input isn't valid JSON, see [`CheckError::input_error`].
+    #[must_use]
+    pub fn errors(&self) -> &[OwnedError] {
+        &self.errors
+    }
+
+    /// The error of parsing or serializing the input, if it isn't valid JSON.
+    #[must_use]
+    pub fn input_error(&self) -> Option<&serde_json::Error> {
+        self.input_error.as_ref()
+    }
+
+    /// The rendered diagnostic, pointing at the invalid values in the JSON input.
+    #[must_use]
+    pub fn report(&self) -> &str {
+        &self.report
+    }
+}
+
+impl std::error::Error for CheckError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let error: &(dyn std::error::Error + 'static) = self.input_error.as_ref()?;
+        Some(error)
+    }
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.report)
+    }
+}
+
+impl fmt::Debug for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Print the diagnostic as is so a `#[test]` returning a `Result` shows
+        // the same output as `assert_json!`.
+        f.write_str(&self.report)
+    }
+}
+
+/// Check that a JSON input matches a validator, without panicking.
+///
+/// This is the function form of [`check_json`!]. Every validation error is
+/// reported, see [`Validator::validate_all`].
+///
+/// ```
+/// use assert_json::{check_json, validators};
+///
+/// assert!(check_json("5", &validators::eq(5)).is_ok());
+/// assert!(check_json("null", &validators::eq(5)).is_err());
+/// ```
+pub fn check_json<I>(input: I, validator: &dyn Validator) -> Result<(), CheckError>
+where
+    I: Into<Input>,
+{
+    let (json, source, notes) = input.into().into_parts();
+    let json = json.map_err(|error| CheckError::invalid_input(source.as_deref(), error))?;
+    validator
+        .validate_all(&json)
+        .map_err(|errors| CheckError::new(&json, source.as_deref(), errors, notes))
 }
 
 /// Custom validators for different JSON types
@@ -178,3 +337,13 @@ pub mod validators;
 mod macros;
 #[doc(hidden)]
 pub mod macros_utils;
+
+pub use error::{Error, ErrorKind, JsonType, OwnedError};
+pub use macros_utils::Input;
+pub use pointer::JsonPointer;
+
+mod base64;
+mod diff;
+mod error;
+mod pointer;
+mod source_spans;
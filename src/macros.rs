@@ -1,20 +1,104 @@
 /// Assert that a json value matches its validation rules
 ///
-/// `$val` parameter can be either a string or a `assert_json::Value`.
+/// `$val` parameter can be JSON text (`&str`, `String`, `Cow<str>`, `Box<str>`, `&[u8]`,
+/// `Vec<u8>`, ...), a `assert_json::Value` or any type implementing `serde::Serialize`.
+/// Other text types are serialized as a JSON string.
 /// `validators` is the validation rule expressed as a JSON-like structure.
+///
+/// A key followed by `?` is optional: its value is only validated if the key
+/// is present.
+///
+/// ```
+/// # use assert_json::assert_json;
+/// assert_json!(r#"{ "name": "charlesvdv" }"#, {
+///     "name": "charlesvdv",
+///     "nickname"?: "charles",
+/// });
+/// ```
+///
+/// Objects ignore the keys which are not specified. An object prefixed by
+/// `strict` fails if the input contains other keys. `absent` asserts a key is
+/// not present.
+///
+/// ```
+/// # use assert_json::assert_json;
+/// assert_json!(r#"{ "user": { "name": "charlesvdv" } }"#, {
+///     "user": strict {
+///         "name": "charlesvdv",
+///         "password": absent,
+///     },
+/// });
+/// ```
+///
+/// Arrays match exactly all their elements. A `..` rest pattern ignores any
+/// number of elements at the start, the end or in the middle of an array.
+///
+/// ```
+/// # use assert_json::assert_json;
+/// assert_json!("[1, 2, 3, 4]", [1, 2, ..]);
+/// assert_json!("[1, 2, 3, 4]", [.., 4]);
+/// assert_json!("[1, 2, 3, 4]", [1, .., 4]);
+/// ```
 #[macro_export]
 macro_rules! assert_json {
+    ($val:expr , $($validator:tt)+) => ({
+        if let Err(error) = $crate::check_json!($val, $($validator)+) {
+            panic!("{}", error);
+        }
+    });
+}
+
+/// Check that a json value matches its validation rules, without panicking.
+///
+/// It accepts the same syntax as [`assert_json`!] but returns a
+/// `Result<(), assert_json::CheckError>` instead of panicking, so it can be
+/// used with `?` in tests and helpers.
+///
+/// ```
+/// use assert_json::{check_json, CheckError};
+///
+/// fn check_user(json: &str) -> Result<(), CheckError> {
+///     check_json!(json, {
+///         "name": "charlesvdv",
+///     })
+/// }
+///
+/// assert!(check_user(r#"{ "name": "charlesvdv" }"#).is_ok());
+/// assert!(check_user(r#"{ "name": "someone else" }"#).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_json {
     ($val:expr , $($validator:tt)+) => ({
         #[allow(unused_imports)]
         use $crate::Validator;
+        #[allow(unused_imports)]
         use $crate::macros_utils::*;
 
-        let validator = $crate::expand_json_validator!($($validator)+);
-        let input = Into::<Input>::into($val).get();
-        let result = validator.validate(&input);
-        if let Err(error) = result {
-            panic!("{}", format_error(&input, &error));
-        }
+        let input = (&InputWrapper::new($val)).to_input();
+        let validator = $crate::json_validator!($($validator)+);
+        $crate::check_json(input, &validator)
+    });
+}
+
+/// Create a [`Validator`](crate::Validator) from the validation rules syntax of
+/// [`assert_json`!].
+///
+/// It is useful to nest validation rules in validators taking another
+/// validator, like [`validators::json_string`](crate::validators::json_string).
+///
+/// ```
+/// use assert_json::{json_validator, Validator};
+///
+/// let validator = json_validator!({ "id": 1 });
+/// assert!(validator.validate(&serde_json::json!({ "id": 1 })).is_ok());
+/// ```
+#[macro_export]
+macro_rules! json_validator {
+    ($($validator:tt)+) => ({
+        #[allow(unused_imports)]
+        use $crate::macros_utils::*;
+
+        $crate::expand_json_validator!($($validator)+)
     });
 }
 
@@ -29,12 +113,22 @@ macro_rules! expand_json_validator {
 
     // Done with trailing comma.
     (@array [$($elems:expr,)*]) => {
-        $crate::expand_json_vec_validator![$($elems,)*]
+        $crate::validators::array($crate::expand_json_vec_validator![$($elems,)*])
     };
 
     // Done without trailing comma.
     (@array [$($elems:expr),*]) => {
-        $crate::expand_json_vec_validator![$($elems),*]
+        $crate::validators::array($crate::expand_json_vec_validator![$($elems),*])
+    };
+
+    // Rest pattern followed by comma, the next elements are the suffix.
+    (@array [$($elems:expr,)*] .. , $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($elems,)*] [] $($rest)*)
+    };
+
+    // Rest pattern as the last element.
+    (@array [$($elems:expr,)*] ..) => {
+        $crate::expand_json_validator!(@array_suffix [$($elems,)*] [])
     };
 
     // Next element is `null`.
@@ -47,6 +141,11 @@ macro_rules! expand_json_validator {
         $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!([$($array)*]))] $($rest)*)
     };
 
+    // Next element is a strict map.
+    (@array [$($elems:expr,)*] strict {$($map:tt)*} $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!(strict {$($map)*}))] $($rest)*)
+    };
+
     // Next element is a map.
     (@array [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
         $crate::expand_json_validator!(@array [$($elems,)* Box::new($crate::expand_json_validator!({$($map)*}))] $($rest)*)
@@ -72,15 +171,102 @@ macro_rules! expand_json_validator {
         $crate::json_unexpected!($unexpected)
     };
 
+    // *******************************************************************
+    // array suffix handling, after a `..` rest pattern
+    // *******************************************************************
+
+    // Done with trailing comma.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*]) => {
+        $crate::validators::array_prefix_suffix(
+            $crate::expand_json_vec_validator![$($prefix,)*],
+            $crate::expand_json_vec_validator![$($elems,)*],
+        )
+    };
+
+    // Done without trailing comma.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr),*]) => {
+        $crate::validators::array_prefix_suffix(
+            $crate::expand_json_vec_validator![$($prefix,)*],
+            $crate::expand_json_vec_validator![$($elems),*],
+        )
+    };
+
+    // Only one rest pattern is allowed.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] .. $($rest:tt)*) => {
+        $crate::json_unexpected!(..)
+    };
+
+    // Next element is `null`.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* Box::new($crate::expand_json_validator!(null))] $($rest)*)
+    };
+
+    // Next element is an array.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* Box::new($crate::expand_json_validator!([$($array)*]))] $($rest)*)
+    };
+
+    // Next element is a strict map.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] strict {$($map:tt)*} $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* Box::new($crate::expand_json_validator!(strict {$($map)*}))] $($rest)*)
+    };
+
+    // Next element is a map.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] {$($map:tt)*} $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* Box::new($crate::expand_json_validator!({$($map)*}))] $($rest)*)
+    };
+
+    // Next element is an expression followed by comma.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* $crate::expand_json_validator!($next),] $($rest)*)
+    };
+
+    // Last element is an expression with no trailing comma.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr,)*] $last:expr) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)* $crate::expand_json_validator!($last)])
+    };
+
+    // Comma after the most recent element.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::expand_json_validator!(@array_suffix [$($prefix,)*] [$($elems,)*] $($rest)*)
+    };
+
+    // Unexpected token after most recent element.
+    (@array_suffix [$($prefix:expr,)*] [$($elems:expr),*] $unexpected:tt $($rest:tt)*) => {
+        $crate::json_unexpected!($unexpected)
+    };
+
     // *******************************************************************
     // object handling
     // *******************************************************************
 
     (@object $object:ident () () ()) => {};
 
+    // Insert the current absent entry followed by trailing comma.
+    (@object $object:ident [@absent $($key:tt)+] , $($rest:tt)*) => {
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Absent));
+        $crate::expand_json_validator!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    // Insert the last absent entry without trailing comma.
+    (@object $object:ident [@absent $($key:tt)+]) => {
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Absent));
+    };
+
+    // Insert the current optional entry followed by trailing comma.
+    (@object $object:ident [@optional $($key:tt)+] ($value:expr) , $($rest:tt)*) => {
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Optional($value)));
+        $crate::expand_json_validator!(@object $object () ($($rest)*) ($($rest)*));
+    };
+
+    // Insert the last optional entry without trailing comma.
+    (@object $object:ident [@optional $($key:tt)+] ($value:expr)) => {
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Optional($value)));
+    };
+
     // Insert the current entry followed by trailing comma.
     (@object $object:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
-        let _unused = $object.insert(($($key)+).into(), $value);
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Required($value)));
         $crate::expand_json_validator!(@object $object () ($($rest)*) ($($rest)*));
     };
 
@@ -91,7 +277,7 @@ macro_rules! expand_json_validator {
 
     // Insert the last entry without trailing comma.
     (@object $object:ident [$($key:tt)+] ($value:expr)) => {
-        let _unused = $object.insert(($($key)+).into(), $value);
+        $object.push((($($key)+).into(), $crate::validators::ObjectEntry::Required($value)));
     };
 
     // Next value is `null`.
@@ -99,11 +285,26 @@ macro_rules! expand_json_validator {
         $crate::expand_json_validator!(@object $object [$($key)+] (Box::new($crate::expand_json_validator!(null))) $($rest)*);
     };
 
+    // Next value is `absent`, the key must not be present.
+    (@object $object:ident ($($key:tt)+) (: absent , $($rest:tt)*) $copy:tt) => {
+        $crate::expand_json_validator!(@object $object [@absent $($key)+] , $($rest)*);
+    };
+
+    // Last value is `absent`, the key must not be present.
+    (@object $object:ident ($($key:tt)+) (: absent) $copy:tt) => {
+        $crate::expand_json_validator!(@object $object [@absent $($key)+]);
+    };
+
     // Next value is an array.
     (@object $object:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*) $copy:tt) => {
         $crate::expand_json_validator!(@object $object [$($key)+] (Box::new($crate::expand_json_validator!([$($array)*]))) $($rest)*);
     };
 
+    // Next value is a strict map.
+    (@object $object:ident ($($key:tt)+) (: strict {$($map:tt)*} $($rest:tt)*) $copy:tt) => {
+        $crate::expand_json_validator!(@object $object [$($key)+] (Box::new($crate::expand_json_validator!(strict {$($map)*}))) $($rest)*);
+    };
+
     // Next value is a map.
     (@object $object:ident ($($key:tt)+) (: {$($map:tt)*} $($rest:tt)*) $copy:tt) => {
         $crate::expand_json_validator!(@object $object [$($key)+] (Box::new($crate::expand_json_validator!({$($map)*}))) $($rest)*);
@@ -155,6 +356,11 @@ macro_rules! expand_json_validator {
         $crate::json_expect_expr_comma!($($unexpected)+);
     };
 
+    // Key is followed by `?:`, the entry is optional.
+    (@object $object:ident ($($key:tt)+) (? : $($rest:tt)*) $copy:tt) => {
+        $crate::expand_json_validator!(@object $object (@optional $($key)+) (: $($rest)*) (: $($rest)*));
+    };
+
     // Munch a token into the current key.
     (@object $object:ident ($($key:tt)*) ($tt:tt $($rest:tt)*) $copy:tt) => {
         $crate::expand_json_validator!(@object $object ($($key)* $tt) ($($rest)*) ($($rest)*));
@@ -173,20 +379,28 @@ macro_rules! expand_json_validator {
     };
 
     ([ $($tt:tt)+ ]) => {
-        // {
-        //     let mut validators_array = vec![];
-        // }
-        $crate::validators::array($crate::expand_json_validator!(@array [] $($tt)+))
-        // $crate::Value::Array(json_internal!(@array [] $($tt)+))
+        $crate::expand_json_validator!(@array [] $($tt)+)
     };
 
     ({}) => {
-        $crate::validators::object(std::collections::HashMap::new())
+        $crate::validators::object(std::vec::Vec::new())
     };
 
     ({ $($tt:tt)+ }) => {
-        $crate::validators::object({
-            let mut object: std::collections::HashMap<String, Box<dyn $crate::Validator>> = std::collections::HashMap::new();
+        $crate::validators::object_entries({
+            let mut object: std::vec::Vec<(String, $crate::validators::ObjectEntry)> = std::vec::Vec::new();
+            $crate::expand_json_validator!(@object object () ($($tt)+) ($($tt)+));
+            object
+        })
+    };
+
+    (strict {}) => {
+        $crate::validators::object_empty()
+    };
+
+    (strict { $($tt:tt)+ }) => {
+        $crate::validators::object_entries_strict({
+            let mut object: std::vec::Vec<(String, $crate::validators::ObjectEntry)> = std::vec::Vec::new();
             $crate::expand_json_validator!(@object object () ($($tt)+) ($($tt)+));
             object
         })
@@ -230,6 +444,96 @@ mod test {
         assert_json!(serde_json::json!("hello"), "hello");
     }
 
+    #[test]
+    fn assert_json_with_text_input() {
+        assert_json!(String::from("[1]"), [1]);
+        assert_json!(&String::from("[1]"), [1]);
+        assert_json!(b"[1]".as_slice(), [1]);
+        assert_json!(b"[1]".to_vec(), [1]);
+        assert_json!(&serde_json::json!([1]), [1]);
+    }
+
+    #[test]
+    fn assert_json_with_borrowed_text_input() {
+        let bodies = ["[1]", "[1]"];
+        for body in &bodies {
+            assert_json!(body, [1]);
+        }
+        let bodies = vec![String::from("[1]")];
+        for body in &bodies {
+            assert_json!(body, [1]);
+        }
+        assert_json!(std::borrow::Cow::Borrowed("[1]"), [1]);
+        assert_json!(&std::borrow::Cow::<str>::Owned(String::from("[1]")), [1]);
+        assert_json!(b"[1]", [1]);
+        assert_json!(*b"[1]", [1]);
+        assert_json!(&b"[1]".to_vec(), [1]);
+
+        let mut body = String::from("[1]");
+        assert_json!(&mut body, [1]);
+        assert_json!(body.as_mut_str(), [1]);
+        assert_json!(Box::<str>::from("[1]"), [1]);
+        assert_json!(std::rc::Rc::<str>::from("[1]"), [1]);
+        assert_json!(std::sync::Arc::<str>::from("[1]"), [1]);
+    }
+
+    #[test]
+    fn check_json_serialized_text_note() {
+        let error = check_json!(&&&r#"{"a": 1}"#, { "a": 1 }).unwrap_err();
+        assert!(error.report().contains(
+            "help: the input was serialized to a JSON string, convert it to `&str` or `String` \
+            to parse it as JSON text"
+        ));
+
+        let error = check_json!(Some("text"), 5).unwrap_err();
+        assert!(!error.report().contains("help:"));
+    }
+
+    #[test]
+    fn assert_json_with_serialize_input() {
+        let mut map = std::collections::BTreeMap::new();
+        map.insert("key", vec![Some(1), None]);
+
+        assert_json!(map, { "key": [1, null] });
+        assert_json!(&(1, "str"), [1, "str"]);
+        assert_json!(Option::<u8>::None, null);
+    }
+
+    #[test]
+    fn check_json_serialize_error() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(vec![1], 1);
+
+        let error = check_json!(map, {}).unwrap_err();
+        assert!(error.input_error().is_some());
+        assert!(error
+            .report()
+            .contains("Failed to serialize the input to JSON"));
+    }
+
+    #[test]
+    fn assert_json_number_representation() {
+        assert_json!("5.0", 5);
+        assert_json!("5", 5.0);
+        assert_json!("[1.0, 2]", [1, 2.0]);
+    }
+
+    #[test]
+    fn assert_json_ranges() {
+        assert_json!(r#"{ "age": 26, "score": 0.5 }"#, {
+            "age": 18..=99,
+            "score": 0.0..1.0,
+        });
+        assert_json!("[1, 2, 3]", [..2, 2.., 3]);
+        assert_json!("[-1]", [..=-1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_range_not_valid() {
+        assert_json!(r#"{ "age": 7 }"#, { "age": 18..=99 });
+    }
+
     #[test]
     fn assert_json_null() {
         assert_json!("null", null);
@@ -290,6 +594,70 @@ mod test {
         );
     }
 
+    #[test]
+    fn assert_json_object_optional_key() {
+        assert_json!(r#"{ "name": "charlesvdv" }"#, {
+            "name": "charlesvdv",
+            "nickname"?: "charles",
+            "age"?: { "value": 5 }
+        });
+        assert_json!(r#"{ "name": "charlesvdv", "nickname": "charles" }"#, {
+            "name": "charlesvdv",
+            "nickname"?: "charles",
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_object_optional_key_err() {
+        assert_json!(r#"{ "nickname": null }"#, {
+            "nickname"?: "charles",
+        });
+    }
+
+    #[test]
+    fn assert_json_object_strict() {
+        assert_json!("{}", strict {});
+        assert_json!(r#"{ "key": { "inner": 1 } }"#, strict {
+            "key": strict { "inner": 1 },
+            "optional"?: null,
+        });
+        assert_json!(r#"[{ "key": 1 }]"#, [strict { "key": 1 }]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_object_strict_err() {
+        assert_json!(r#"{ "key": 1, "other": 2 }"#, strict { "key": 1 });
+    }
+
+    #[test]
+    #[expect(
+        clippy::vec_init_then_push,
+        reason = "the macro pushes the object entries one by one"
+    )]
+    fn assert_json_object_absent_key() {
+        assert_json!(r#"{ "name": "charlesvdv" }"#, {
+            "name": "charlesvdv",
+            "password": absent,
+        });
+        assert_json!(r#"{ "name": "charlesvdv" }"#, {
+            "password": absent
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    #[expect(
+        clippy::vec_init_then_push,
+        reason = "the macro pushes the object entries one by one"
+    )]
+    fn assert_json_object_absent_key_err() {
+        assert_json!(r#"{ "password": "secret" }"#, {
+            "password": absent,
+        });
+    }
+
     #[test]
     fn assert_json_array_empty() {
         assert_json!("[]", []);
@@ -302,6 +670,10 @@ mod test {
     }
 
     #[test]
+    #[expect(
+        clippy::vec_init_then_push,
+        reason = "the macro pushes the object entries one by one"
+    )]
     fn assert_json_array() {
         assert_json!(
             r#"[
@@ -331,6 +703,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn assert_json_array_rest() {
+        assert_json!("[]", [..]);
+        assert_json!("[1, 2, 3]", [..]);
+        assert_json!("[1, 2, 3]", [1, ..]);
+        assert_json!("[1, 2, 3]", [1, 2, 3, ..]);
+        assert_json!("[1, 2, 3]", [.., 3]);
+        assert_json!("[1, 2, 3]", [.., 2, 3,]);
+        assert_json!("[1, 2, 3]", [1, .., 3]);
+        assert_json!(
+            r#"[null, [1], { "key": 1 }, 5, { "key": 2 }, [2], null]"#,
+            [null, [1], { "key": 1 }, .., strict { "key": 2 }, [2], null]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_array_rest_too_short() {
+        assert_json!("[1]", [1, .., 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_json_array_rest_err() {
+        assert_json!("[1, 2, 3]", [.., 2]);
+    }
+
     #[test]
     fn assert_json_custom_validator() {
         assert_json!("null", crate::validators::any());
@@ -364,4 +763,57 @@ mod test {
         let num = 5;
         assert_json!("5", num);
     }
+
+    #[test]
+    fn check_json_ok() {
+        assert!(check_json!(r#"{ "key": [1, 2] }"#, { "key": [1, 2] }).is_ok());
+    }
+
+    #[test]
+    fn check_json_err() {
+        let error = check_json!(r#"{ "key": [1, 2] }"#, { "key": [1, 3] }).unwrap_err();
+
+        assert_eq!(1, error.errors().len());
+        assert_eq!(&serde_json::json!(2), error.errors()[0].value());
+        assert_eq!(
+            "/key/1: Invalid value. Expected 3 but got 2.",
+            error.errors()[0].to_string()
+        );
+        assert!(error.report().contains(&error.errors()[0].to_string()));
+    }
+
+    #[test]
+    fn check_json_errors_in_key_order() {
+        let error = check_json!(r#"{ "a": 1, "b": 2, "c": 3 }"#, {
+            "c": 0,
+            "a": 0,
+            "missing": 0,
+            "b": 0,
+        })
+        .unwrap_err();
+
+        let paths: Vec<_> = error
+            .errors()
+            .iter()
+            .map(|error| error.path().to_string())
+            .collect();
+        assert_eq!(vec!["/c", "/a", "", "/b"], paths);
+    }
+
+    #[test]
+    fn check_json_invalid_input() {
+        let error = check_json!("[1, 2", [1, 2]).unwrap_err();
+
+        assert!(error.errors().is_empty());
+        assert!(error.input_error().is_some());
+        assert!(error
+            .report()
+            .contains("Failed to parse JSON (unexpected end of input)"));
+    }
+
+    #[test]
+    fn check_json_with_question_mark() -> Result<(), crate::CheckError> {
+        check_json!("null", null)?;
+        check_json!("[true]", [true])
+    }
 }
@@ -1,33 +1,260 @@
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::BTreeMap;
+use std::fmt::Write as _;
 use std::io::IsTerminal as _;
-use std::ops::Range;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::rc::Rc;
+use std::sync::Arc;
 
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::files::SimpleFiles;
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor;
+use serde::Serialize;
 
+use crate::source_spans::SourceSpans;
 use crate::{validators, Error, Validator, Value};
 
-pub struct Input(Value);
+/// JSON input accepted by [`assert_json`!](crate::assert_json) and
+/// [`check_json`!](crate::check_json!).
+///
+/// It can be created from JSON text (`&str`, `String`, `Cow<str>`, `Box<str>`,
+/// `Rc<str>`, `Arc<str>`, `&[u8]`, `Vec<u8>`, byte arrays and references to
+/// them), from a [Value] or from any [`Serialize`] type with
+/// [`Input::serialize`]. When created from JSON text, the diagnostics point
+/// into the original text.
+///
+/// [`assert_json`!](crate::assert_json) and [`check_json`!](crate::check_json!)
+/// directly accept any [`Serialize`] type.
+pub struct Input {
+    value: Result<Value, serde_json::Error>,
+    source: Option<String>,
+    notes: Vec<String>,
+}
 
 impl Input {
+    /// Serialize `value` to JSON.
+    ///
+    /// A text type serializes to a JSON string: if the validation fails and
+    /// the string contains JSON, the diagnostic suggests to parse it instead.
+    pub fn serialize<T>(value: &T) -> Input
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = serde_json::to_value(value);
+        let notes = match &value {
+            Ok(Value::String(text)) if serde_json::from_str::<Value>(text).is_ok() => {
+                vec![String::from(
+                    "help: the input was serialized to a JSON string, convert it to `&str` or \
+                    `String` to parse it as JSON text",
+                )]
+            }
+            _ => vec![],
+        };
+        Input {
+            value,
+            source: None,
+            notes,
+        }
+    }
+
+    /// Returns the JSON value.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diagnostic of the parsing error if the input isn't valid JSON.
     #[must_use]
     pub fn get(self) -> Value {
-        self.0
+        match self.value {
+            Ok(value) => value,
+            Err(error) => panic!("{}", format_input_error(self.source.as_deref(), &error)),
+        }
+    }
+
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        Result<Value, serde_json::Error>,
+        Option<String>,
+        Vec<String>,
+    ) {
+        (self.value, self.source, self.notes)
     }
 }
 
 impl From<&str> for Input {
     fn from(str_input: &str) -> Input {
-        let value = serde_json::from_str(str_input).expect("failed to parse JSON");
-        Input(value)
+        Input {
+            value: serde_json::from_str(str_input),
+            source: Some(str_input.to_string()),
+            notes: vec![],
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(str_input: String) -> Input {
+        Input {
+            value: serde_json::from_str(&str_input),
+            source: Some(str_input),
+            notes: vec![],
+        }
+    }
+}
+
+impl From<&String> for Input {
+    fn from(str_input: &String) -> Input {
+        Input::from(str_input.as_str())
+    }
+}
+
+impl From<&&str> for Input {
+    fn from(str_input: &&str) -> Input {
+        Input::from(*str_input)
+    }
+}
+
+impl From<&&String> for Input {
+    fn from(str_input: &&String) -> Input {
+        Input::from(str_input.as_str())
+    }
+}
+
+impl From<&mut str> for Input {
+    fn from(str_input: &mut str) -> Input {
+        Input::from(&*str_input)
+    }
+}
+
+impl From<&mut String> for Input {
+    fn from(str_input: &mut String) -> Input {
+        Input::from(str_input.as_str())
+    }
+}
+
+impl From<Box<str>> for Input {
+    fn from(str_input: Box<str>) -> Input {
+        Input::from(String::from(str_input))
+    }
+}
+
+impl From<Rc<str>> for Input {
+    fn from(str_input: Rc<str>) -> Input {
+        Input::from(&*str_input)
+    }
+}
+
+impl From<Arc<str>> for Input {
+    fn from(str_input: Arc<str>) -> Input {
+        Input::from(&*str_input)
+    }
+}
+
+impl From<Cow<'_, str>> for Input {
+    fn from(str_input: Cow<'_, str>) -> Input {
+        Input::from(str_input.into_owned())
+    }
+}
+
+impl From<&Cow<'_, str>> for Input {
+    fn from(str_input: &Cow<'_, str>) -> Input {
+        Input::from(str_input.as_ref())
+    }
+}
+
+impl From<&[u8]> for Input {
+    fn from(bytes_input: &[u8]) -> Input {
+        Input {
+            value: serde_json::from_slice(bytes_input),
+            source: Some(String::from_utf8_lossy(bytes_input).into_owned()),
+            notes: vec![],
+        }
+    }
+}
+
+impl From<Vec<u8>> for Input {
+    fn from(bytes_input: Vec<u8>) -> Input {
+        Input::from(bytes_input.as_slice())
+    }
+}
+
+impl From<&Vec<u8>> for Input {
+    fn from(bytes_input: &Vec<u8>) -> Input {
+        Input::from(bytes_input.as_slice())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for Input {
+    fn from(bytes_input: &[u8; N]) -> Input {
+        Input::from(bytes_input.as_slice())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Input {
+    fn from(bytes_input: [u8; N]) -> Input {
+        Input::from(bytes_input.as_slice())
     }
 }
 
 impl From<Value> for Input {
     fn from(value: Value) -> Input {
-        Input(value)
+        Input {
+            value: Ok(value),
+            source: None,
+            notes: vec![],
+        }
+    }
+}
+
+impl From<&Value> for Input {
+    fn from(value: &Value) -> Input {
+        Input::from(value.clone())
+    }
+}
+
+/// Wraps the input of [`check_json`!](crate::check_json!) to select how it is
+/// converted into an [Input].
+///
+/// Types implementing `Into<Input>` are converted with [`ConvertInput`], any other
+/// [`Serialize`] type is serialized with [`SerializeInput`]. The selection relies
+/// on method resolution preferring `&InputWrapper<T>` receivers over
+/// `&&InputWrapper<T>` ones.
+pub struct InputWrapper<T>(Cell<Option<T>>);
+
+impl<T> InputWrapper<T> {
+    pub fn new(input: T) -> InputWrapper<T> {
+        InputWrapper(Cell::new(Some(input)))
+    }
+
+    fn take(&self) -> T {
+        self.0.take().expect("input already converted")
+    }
+}
+
+pub trait ConvertInput {
+    fn to_input(&self) -> Input;
+}
+
+impl<T> ConvertInput for InputWrapper<T>
+where
+    T: Into<Input>,
+{
+    fn to_input(&self) -> Input {
+        self.take().into()
+    }
+}
+
+pub trait SerializeInput {
+    fn to_input(&self) -> Input;
+}
+
+impl<T> SerializeInput for &InputWrapper<T>
+where
+    T: Serialize,
+{
+    fn to_input(&self) -> Input {
+        Input::serialize(&self.take())
     }
 }
 
@@ -60,6 +287,47 @@ impl_from_validator_input_default!(
     String, bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64
 );
 
+#[doc(hidden)]
+macro_rules! impl_from_validator_input_range {
+    (
+        $($ty:ty),*
+    ) => {
+        $(
+            impl From<Range<$ty>> for ValidatorInput {
+                fn from(range: Range<$ty>) -> Self {
+                    ValidatorInput(Box::new(validators::in_range(range)))
+                }
+            }
+
+            impl From<RangeInclusive<$ty>> for ValidatorInput {
+                fn from(range: RangeInclusive<$ty>) -> Self {
+                    ValidatorInput(Box::new(validators::in_range(range)))
+                }
+            }
+
+            impl From<RangeFrom<$ty>> for ValidatorInput {
+                fn from(range: RangeFrom<$ty>) -> Self {
+                    ValidatorInput(Box::new(validators::in_range(range)))
+                }
+            }
+
+            impl From<RangeTo<$ty>> for ValidatorInput {
+                fn from(range: RangeTo<$ty>) -> Self {
+                    ValidatorInput(Box::new(validators::in_range(range)))
+                }
+            }
+
+            impl From<RangeToInclusive<$ty>> for ValidatorInput {
+                fn from(range: RangeToInclusive<$ty>) -> Self {
+                    ValidatorInput(Box::new(validators::in_range(range)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_validator_input_range!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
 impl From<&str> for ValidatorInput {
     fn from(str_input: &str) -> Self {
         ValidatorInput(Box::new(validators::eq(String::from(str_input))))
@@ -77,40 +345,232 @@ where
 
 #[must_use]
 pub fn format_error<'a>(json: &'a Value, error: &Error<'a>) -> String {
-    let serializer = SpanSerializer::serialize(json);
+    format_errors(json, std::slice::from_ref(error))
+}
+
+/// Render multiple validation errors as labels of a single diagnostic.
+#[must_use]
+pub fn format_errors<'a>(json: &'a Value, errors: &[Error<'a>]) -> String {
+    format_errors_in_source(json, None, errors, vec![])
+}
+
+/// Render multiple validation errors as labels of a single diagnostic.
+///
+/// If `json` was parsed from `source`, the labels point into `source` instead
+/// of a serialized version of `json`. The `input_notes` about the input
+/// follow the notes of the errors.
+pub(crate) fn format_errors_in_source<'a>(
+    json: &'a Value,
+    source: Option<&str>,
+    errors: &[Error<'a>],
+    input_notes: Vec<String>,
+) -> String {
+    let labels: Vec<_> = errors
+        .iter()
+        .map(|error| (error.location(), error.kind().anchor(), error.to_string()))
+        .collect();
+    let notes = errors
+        .iter()
+        .flat_map(Error::notes)
+        .chain(input_notes)
+        .collect();
+    format_labels(json, source, &labels, notes)
+}
+
+/// The part of a value a diagnostic label points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Anchor {
+    /// The whole value.
+    Value,
+    /// The key of the value in its parent object, or the whole value if it
+    /// has no key.
+    Key,
+    /// The position before the closing brace of an object, where a missing key
+    /// would be inserted.
+    ObjectEnd,
+}
+
+/// Render a diagnostic with one label per `(location, anchor, message)` triple.
+pub(crate) fn format_labels(
+    json: &Value,
+    source: Option<&str>,
+    labels: &[(&Value, Anchor, String)],
+    notes: Vec<String>,
+) -> String {
+    let spans = JsonSpans::new(json, source);
 
     let mut files = SimpleFiles::new();
-    let file = files.add("", serializer.serialized_json());
+    let file = files.add("", spans.text());
 
+    let message = if labels.len() > 1 {
+        format!("Invalid JSON ({} errors)", labels.len())
+    } else {
+        String::from("Invalid JSON")
+    };
+    let labels = labels
+        .iter()
+        .map(|(location, anchor, message)| {
+            Label::primary(file, spans.anchored_span(location, *anchor)).with_message(message)
+        })
+        .collect();
     let diagnostic = Diagnostic::error()
-        .with_message("Invalid JSON")
-        .with_labels(vec![Label::primary(
-            file,
-            serializer.span(error.location()),
-        )
-        .with_message(error.to_string())]);
+        .with_message(message)
+        .with_labels(labels)
+        .with_notes(notes);
+
+    emit(&files, &diagnostic)
+}
+
+/// Render the error of parsing `source` as JSON, or the error of serializing
+/// the input if there is no `source`.
+pub(crate) fn format_input_error(source: Option<&str>, error: &serde_json::Error) -> String {
+    let Some(source) = source else {
+        let files = SimpleFiles::new();
+        let diagnostic = Diagnostic::error()
+            .with_message("Failed to serialize the input to JSON")
+            .with_notes(vec![error.to_string()]);
+        return emit(&files, &diagnostic);
+    };
+
+    let mut files = SimpleFiles::new();
+    let file = files.add("", source);
+
+    let message = error.to_string();
+    // The position is already displayed by the diagnostic.
+    let message = message
+        .rsplit_once(" at line ")
+        .map_or(message.as_str(), |(message, _)| message);
+
+    let category = match error.classify() {
+        serde_json::error::Category::Io => "I/O error",
+        serde_json::error::Category::Syntax => "syntax error",
+        serde_json::error::Category::Data => "invalid data",
+        serde_json::error::Category::Eof => "unexpected end of input",
+    };
 
+    let mut span = input_error_span(source, error);
+    let next_char = source[span.start..].chars().next();
+    let mut notes = vec![];
+    if message == "trailing comma" {
+        // serde_json points after the comma, underline the comma itself.
+        if let Some(comma) = source[..span.start].trim_end().strip_suffix(',') {
+            span = comma.len()..comma.len() + 1;
+        }
+        notes.push(String::from(
+            "help: JSON doesn't allow trailing commas, remove the last `,`",
+        ));
+    } else if next_char == Some('\'') {
+        notes.push(String::from(
+            "help: JSON strings must be enclosed in double quotes `\"`, not single quotes",
+        ));
+    } else if message == "key must be a string"
+        && next_char.is_some_and(|c| c.is_alphabetic() || c == '_')
+    {
+        notes.push(String::from(
+            "help: JSON object keys must be enclosed in double quotes `\"`",
+        ));
+    }
+
+    let diagnostic = Diagnostic::error()
+        .with_message(format!("Failed to parse JSON ({category})"))
+        .with_labels(vec![Label::primary(file, span).with_message(message)])
+        .with_notes(notes);
+
+    emit(&files, &diagnostic)
+}
+
+/// Convert the line/column position of a parsing error into a byte span.
+fn input_error_span(source: &str, error: &serde_json::Error) -> Range<usize> {
+    if error.classify() == serde_json::error::Category::Eof {
+        return source.len()..source.len();
+    }
+
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let mut start = (line_start + error.column().saturating_sub(1)).min(source.len());
+    while !source.is_char_boundary(start) {
+        start -= 1;
+    }
+    let len = source[start..].chars().next().map_or(0, char::len_utf8);
+    start..start + len
+}
+
+fn emit(files: &SimpleFiles<&str, &str>, diagnostic: &Diagnostic<usize>) -> String {
     let config = term::Config::default();
     let bytes = Vec::<u8>::new();
 
     let bytes = if std::io::stderr().is_terminal() {
         let mut writer = termcolor::Ansi::new(bytes);
-        term::emit(&mut writer, &config, &files, &diagnostic).unwrap();
+        term::emit(&mut writer, &config, files, diagnostic).unwrap();
         writer.into_inner()
     } else {
         let mut writer = termcolor::NoColor::new(bytes);
-        term::emit(&mut writer, &config, &files, &diagnostic).unwrap();
+        term::emit(&mut writer, &config, files, diagnostic).unwrap();
         writer.into_inner()
     };
 
     String::from_utf8(bytes).unwrap()
 }
 
+/// The JSON text displayed in the diagnostics, with the span of each element.
+enum JsonSpans<'s> {
+    /// The original text the JSON was parsed from.
+    Source(SourceSpans<'s>),
+    /// No original text is available, the JSON is serialized.
+    Serialized(SpanSerializer),
+}
+
+impl<'s> JsonSpans<'s> {
+    fn new(json: &Value, source: Option<&'s str>) -> JsonSpans<'s> {
+        match source.and_then(|source| SourceSpans::parse(source, json)) {
+            Some(source_spans) => JsonSpans::Source(source_spans),
+            None => JsonSpans::Serialized(SpanSerializer::serialize(json)),
+        }
+    }
+
+    fn text(&self) -> &str {
+        match self {
+            JsonSpans::Source(source_spans) => source_spans.source(),
+            JsonSpans::Serialized(serializer) => serializer.serialized_json(),
+        }
+    }
+
+    fn span(&self, val: &Value) -> Range<usize> {
+        match self {
+            JsonSpans::Source(source_spans) => source_spans.span(val).expect("expected span"),
+            JsonSpans::Serialized(serializer) => serializer.span(val),
+        }
+    }
+
+    fn key_span(&self, val: &Value) -> Option<Range<usize>> {
+        match self {
+            JsonSpans::Source(source_spans) => source_spans.key_span(val),
+            JsonSpans::Serialized(serializer) => serializer.key_span(val),
+        }
+    }
+
+    fn anchored_span(&self, val: &Value, anchor: Anchor) -> Range<usize> {
+        match anchor {
+            Anchor::Key => self.key_span(val).unwrap_or_else(|| self.span(val)),
+            Anchor::ObjectEnd if val.is_object() => {
+                // Both the source and the serialized JSON end objects with `}`.
+                let end = self.span(val).end - 1;
+                end..end
+            }
+            Anchor::Value | Anchor::ObjectEnd => self.span(val),
+        }
+    }
+}
+
 /// Serialize a JSON [Value] and keeps the span information of each
 /// elements.
 #[derive(Default)]
 struct SpanSerializer {
     spans: BTreeMap<*const Value, Range<usize>>,
+    key_spans: BTreeMap<*const Value, Range<usize>>,
     json: String,
     current_ident: usize,
 }
@@ -127,11 +587,11 @@ impl SpanSerializer {
 
         match input {
             serde_json::Value::Null => self.json.push_str("null"),
-            serde_json::Value::Bool(bool_val) => self.json.push_str(&format!("{bool_val}")),
+            serde_json::Value::Bool(bool_val) => write!(self.json, "{bool_val}").unwrap(),
             serde_json::Value::Number(num_val) => {
                 self.json.push_str(&num_val.to_string());
             }
-            serde_json::Value::String(str_val) => self.json.push_str(&format!("\"{str_val}\"")),
+            serde_json::Value::String(str_val) => self.push_string(str_val),
             serde_json::Value::Array(arr_val) => {
                 self.json.push_str("[\n");
                 self.current_ident += 1;
@@ -155,7 +615,13 @@ impl SpanSerializer {
                         self.json.push_str(",\n");
                     }
                     self.ident();
-                    self.json.push_str(&format!("\"{key}\": "));
+                    let key_start = self.json.len();
+                    self.push_string(key);
+                    self.key_spans.insert(
+                        std::ptr::from_ref::<Value>(value),
+                        key_start..self.json.len(),
+                    );
+                    self.json.push_str(": ");
                     self.serialize_recursive(value);
                 }
                 self.json.push('\n');
@@ -170,6 +636,12 @@ impl SpanSerializer {
             .insert(std::ptr::from_ref::<Value>(input), start..end);
     }
 
+    /// Write a string with the same escaping as `serde_json`.
+    fn push_string(&mut self, str_val: &str) {
+        self.json
+            .push_str(&serde_json::to_string(str_val).expect("string serialization"));
+    }
+
     fn ident(&mut self) {
         self.json.push_str(&" ".repeat(self.current_ident * 4));
     }
@@ -184,6 +656,12 @@ impl SpanSerializer {
             .expect("expected span")
             .clone()
     }
+
+    fn key_span(&self, val: &Value) -> Option<Range<usize>> {
+        self.key_spans
+            .get(&std::ptr::from_ref::<Value>(val))
+            .cloned()
+    }
 }
 
 #[cfg(test)]
@@ -220,6 +698,7 @@ mod tests {
             serializer.serialized_json()
         );
         assert_eq!(35..38, serializer.span(num_value));
+        assert_eq!(Some(26..33), serializer.key_span(num_value));
     }
 
     #[test]
@@ -245,4 +724,41 @@ mod tests {
             serializer.serialized_json()
         );
     }
+
+    #[test]
+    fn serialize_escaped_strings() {
+        let value = serde_json::json!({
+            "a\"b": "quote \" and backslash \\",
+            "multi\nline": "tab\there",
+            "emoji": "😀 𝄞",
+        });
+        let tab_value = &value["multi\nline"];
+
+        let serializer = SpanSerializer::serialize(&value);
+        assert_eq!(
+            indoc! {r#"
+                {
+                    "a\"b": "quote \" and backslash \\",
+                    "emoji": "😀 𝄞",
+                    "multi\nline": "tab\there"
+                }"#},
+            serializer.serialized_json()
+        );
+        assert_eq!(
+            value,
+            serde_json::from_str::<Value>(serializer.serialized_json()).unwrap()
+        );
+        assert_eq!(
+            r#""tab\there""#,
+            &serializer.serialized_json()[serializer.span(tab_value)]
+        );
+        assert_eq!(
+            r#""multi\nline""#,
+            &serializer.serialized_json()[serializer.key_span(tab_value).unwrap()]
+        );
+        assert_eq!(
+            r#""😀 𝄞""#,
+            &serializer.serialized_json()[serializer.span(&value["emoji"])]
+        );
+    }
 }
@@ -0,0 +1,70 @@
+use core::fmt;
+
+/// A [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) identifying a value
+/// inside a JSON document, like `/result/items/3/name`.
+///
+/// The empty pointer identifies the whole document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JsonPointer {
+    tokens: Vec<String>,
+}
+
+impl JsonPointer {
+    /// The pointer to the whole document.
+    #[must_use]
+    pub fn root() -> JsonPointer {
+        JsonPointer::default()
+    }
+
+    /// Returns true if the pointer identifies the whole document.
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// The unescaped reference tokens of the pointer.
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().map(String::as_str)
+    }
+
+    /// Insert a reference token at the start of the pointer.
+    pub(crate) fn push_front(&mut self, token: String) {
+        self.tokens.insert(0, token);
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.tokens {
+            write!(f, "/{}", token.replace('~', "~0").replace('/', "~1"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonPointer;
+
+    #[test]
+    fn root() {
+        let pointer = JsonPointer::root();
+
+        assert!(pointer.is_root());
+        assert_eq!("", pointer.to_string());
+    }
+
+    #[test]
+    fn escaped_tokens() {
+        let mut pointer = JsonPointer::root();
+        pointer.push_front(String::from("a/b"));
+        pointer.push_front(String::from("3"));
+        pointer.push_front(String::from("m~n"));
+
+        assert_eq!("/m~0n/3/a~1b", pointer.to_string());
+        assert_eq!(
+            vec!["m~n", "3", "a/b"],
+            pointer.tokens().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::Value;
+
+/// Span of each element of a JSON [Value] inside the text it was parsed from.
+///
+/// The text is scanned alongside the already parsed [Value], so the scanner
+/// only has to find where each element starts and ends.
+pub(crate) struct SourceSpans<'s> {
+    source: &'s str,
+    spans: BTreeMap<*const Value, Range<usize>>,
+    key_spans: BTreeMap<*const Value, Range<usize>>,
+}
+
+impl<'s> SourceSpans<'s> {
+    /// Record the spans of `value` in `source`.
+    ///
+    /// Returns `None` if `value` wasn't parsed from `source`.
+    pub(crate) fn parse(source: &'s str, value: &Value) -> Option<SourceSpans<'s>> {
+        let mut scanner = Scanner {
+            source,
+            pos: 0,
+            spans: BTreeMap::new(),
+            key_spans: BTreeMap::new(),
+        };
+        scanner.scan_value(value)?;
+        scanner.skip_whitespaces();
+        if scanner.pos != source.len() {
+            return None;
+        }
+
+        Some(SourceSpans {
+            source,
+            spans: scanner.spans,
+            key_spans: scanner.key_spans,
+        })
+    }
+
+    pub(crate) fn source(&self) -> &str {
+        self.source
+    }
+
+    pub(crate) fn span(&self, val: &Value) -> Option<Range<usize>> {
+        self.spans.get(&std::ptr::from_ref::<Value>(val)).cloned()
+    }
+
+    /// The span of the key of `val` in its parent object.
+    pub(crate) fn key_span(&self, val: &Value) -> Option<Range<usize>> {
+        self.key_spans
+            .get(&std::ptr::from_ref::<Value>(val))
+            .cloned()
+    }
+}
+
+struct Scanner<'s> {
+    source: &'s str,
+    pos: usize,
+    spans: BTreeMap<*const Value, Range<usize>>,
+    key_spans: BTreeMap<*const Value, Range<usize>>,
+}
+
+impl Scanner<'_> {
+    fn scan_value(&mut self, value: &Value) -> Option<()> {
+        self.skip_whitespaces();
+        let start = self.pos;
+
+        match value {
+            Value::Null => self.expect("null")?,
+            Value::Bool(true) => self.expect("true")?,
+            Value::Bool(false) => self.expect("false")?,
+            Value::Number(_) => self.scan_number()?,
+            Value::String(_) => {
+                self.scan_string()?;
+            }
+            Value::Array(items) => {
+                self.expect("[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index != 0 {
+                        self.skip_whitespaces();
+                        self.expect(",")?;
+                    }
+                    self.scan_value(item)?;
+                }
+                self.skip_whitespaces();
+                self.expect("]")?;
+            }
+            Value::Object(object) => {
+                self.expect("{")?;
+                self.skip_whitespaces();
+                if self.peek() != Some(b'}') {
+                    loop {
+                        self.skip_whitespaces();
+                        let key_start = self.pos;
+                        let key = self.scan_string()?;
+                        let key_end = self.pos;
+                        self.skip_whitespaces();
+                        self.expect(":")?;
+                        // With duplicated keys, the last value is kept by the
+                        // parser. As it is also the last one scanned, its spans
+                        // overwrite the spans of the previous ones.
+                        let item = object.get(&key)?;
+                        self.scan_value(item)?;
+                        self.key_spans
+                            .insert(std::ptr::from_ref::<Value>(item), key_start..key_end);
+                        self.skip_whitespaces();
+                        if self.peek() != Some(b',') {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                }
+                self.expect("}")?;
+            }
+        }
+
+        self.spans
+            .insert(std::ptr::from_ref::<Value>(value), start..self.pos);
+        Some(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespaces(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Option<()> {
+        if self.source[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn scan_number(&mut self) -> Option<()> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        (self.pos != start).then_some(())
+    }
+
+    /// Scan a string and returns its unescaped content.
+    fn scan_string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.expect("\"")?;
+        loop {
+            match self.peek()? {
+                b'"' => break,
+                b'\\' => self.pos += 2,
+                _ => self.pos += 1,
+            }
+        }
+        self.pos += 1;
+        serde_json::from_str(self.source.get(start..self.pos)?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceSpans;
+    use crate::Value;
+
+    #[test]
+    fn primitive() {
+        let source = "  true ";
+        let value: Value = serde_json::from_str(source).unwrap();
+
+        let spans = SourceSpans::parse(source, &value).unwrap();
+        assert_eq!(Some(2..6), spans.span(&value));
+    }
+
+    #[test]
+    fn nested() {
+        let source = r#"{"b": [1, "x\"y"], "a": {"c": -2.5e3}}"#;
+        let value: Value = serde_json::from_str(source).unwrap();
+
+        let spans = SourceSpans::parse(source, &value).unwrap();
+        assert_eq!(Some(0..source.len()), spans.span(&value));
+        assert_eq!(Some(6..17), spans.span(&value["b"]));
+        assert_eq!(Some(10..16), spans.span(&value["b"][1]));
+        assert_eq!(Some(30..36), spans.span(&value["a"]["c"]));
+        assert_eq!(Some(25..28), spans.key_span(&value["a"]["c"]));
+        assert_eq!(None, spans.key_span(&value["b"][1]));
+    }
+
+    #[test]
+    fn duplicated_keys() {
+        let source = r#"{"a": 1, "a": 2}"#;
+        let value: Value = serde_json::from_str(source).unwrap();
+
+        let spans = SourceSpans::parse(source, &value).unwrap();
+        assert_eq!(Some(14..15), spans.span(&value["a"]));
+        assert_eq!(Some(9..12), spans.key_span(&value["a"]));
+    }
+
+    #[test]
+    fn different_value() {
+        let value = serde_json::json!([1, 2]);
+
+        assert!(SourceSpans::parse("[1]", &value).is_none());
+    }
+}
@@ -0,0 +1,297 @@
+use core::fmt;
+
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
+
+/// Match a number approximately equal to `expected`.
+///
+/// The tolerances are configured with [`Approx::abs`], [`Approx::rel`] and
+/// [`Approx::ulps`]. The number matches if it is within any of them. Without
+/// any tolerance, the number must be within 4 ULPs of `expected`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!("[2.3000000001]", [validators::approx(2.3).abs(1e-9).rel(1e-6)]);
+/// ```
+#[must_use]
+pub fn approx(expected: f64) -> Approx {
+    Approx {
+        expected,
+        tolerance: Tolerance::default(),
+    }
+}
+
+/// Match a number equal to `expected` within the absolute `tolerance`.
+#[must_use]
+pub fn approx_abs(expected: f64, tolerance: f64) -> Approx {
+    approx(expected).abs(tolerance)
+}
+
+/// Match a number equal to `expected` within the relative `tolerance`.
+#[must_use]
+pub fn approx_rel(expected: f64, tolerance: f64) -> Approx {
+    approx(expected).rel(tolerance)
+}
+
+/// Match a number equal to `expected` within `ulps` units in the last place.
+#[must_use]
+pub fn approx_ulps(expected: f64, ulps: u64) -> Approx {
+    approx(expected).ulps(ulps)
+}
+
+/// Validator created by [`approx`].
+#[derive(Debug, Clone)]
+pub struct Approx {
+    expected: f64,
+    tolerance: Tolerance,
+}
+
+impl Approx {
+    /// Accept numbers whose difference with the expected value is at most
+    /// `tolerance`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is negative, infinite or NaN.
+    #[must_use]
+    pub fn abs(mut self, tolerance: f64) -> Approx {
+        assert_tolerance("absolute", tolerance);
+        self.tolerance.abs = Some(tolerance);
+        self
+    }
+
+    /// Accept numbers whose difference with the expected value is at most
+    /// `tolerance` times the largest magnitude of both numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is negative, infinite or NaN.
+    #[must_use]
+    pub fn rel(mut self, tolerance: f64) -> Approx {
+        assert_tolerance("relative", tolerance);
+        self.tolerance.rel = Some(tolerance);
+        self
+    }
+
+    /// Accept numbers which are at most `ulps` representable floats away from
+    /// the expected value.
+    #[must_use]
+    pub fn ulps(mut self, ulps: u64) -> Approx {
+        self.tolerance.ulps = Some(ulps);
+        self
+    }
+}
+
+fn assert_tolerance(kind: &str, tolerance: f64) {
+    assert!(
+        tolerance >= 0.0 && tolerance.is_finite(),
+        "invalid {kind} tolerance {tolerance}: it must be a finite non-negative number"
+    );
+}
+
+impl Validator for Approx {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let actual = value
+            .as_f64()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Number)))?;
+
+        let tolerance = if self.tolerance == Tolerance::default() {
+            Tolerance::default().with_ulps(4)
+        } else {
+            self.tolerance
+        };
+        if tolerance.matches(self.expected, actual) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                value,
+                ErrorKind::NotApproxEqual {
+                    expected: self.expected,
+                    tolerance,
+                },
+            ))
+        }
+    }
+}
+
+/// Tolerances of an approximate comparison, see [`approx`].
+///
+/// Two numbers are approximately equal if their difference is within any of
+/// the tolerances.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tolerance {
+    pub abs: Option<f64>,
+    pub rel: Option<f64>,
+    pub ulps: Option<u64>,
+}
+
+impl Tolerance {
+    fn with_ulps(mut self, ulps: u64) -> Tolerance {
+        self.ulps = Some(ulps);
+        self
+    }
+
+    /// Returns true if `actual` is within the tolerances of `expected`.
+    #[must_use]
+    pub fn matches(&self, expected: f64, actual: f64) -> bool {
+        #[expect(
+            clippy::float_cmp,
+            reason = "equal numbers are always within tolerance"
+        )]
+        if expected == actual {
+            return true;
+        }
+
+        let difference = (expected - actual).abs();
+        self.abs.is_some_and(|abs| difference <= abs)
+            || self
+                .rel
+                .is_some_and(|rel| difference <= rel * expected.abs().max(actual.abs()))
+            || self
+                .ulps
+                .is_some_and(|ulps| ulps_distance(expected, actual).is_some_and(|d| d <= ulps))
+    }
+
+    /// Describe the difference between `expected` and `actual` for each
+    /// tolerance.
+    pub(crate) fn describe_difference(&self, expected: f64, actual: f64) -> String {
+        let difference = (expected - actual).abs();
+        let mut parts = vec![];
+        if let Some(abs) = self.abs {
+            parts.push(format!("{difference:.2e} (> {abs:e})"));
+        }
+        if let Some(rel) = self.rel {
+            let relative = difference / expected.abs().max(actual.abs());
+            parts.push(format!("{relative:.2e} relative (> {rel:e})"));
+        }
+        if let Some(ulps) = self.ulps {
+            match ulps_distance(expected, actual) {
+                Some(distance) => parts.push(format!("{distance} ULPs (> {ulps})")),
+                None => parts.push(String::from("not comparable in ULPs")),
+            }
+        }
+        parts.join(", ")
+    }
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![];
+        if let Some(abs) = self.abs {
+            parts.push(format!("± {abs:e}"));
+        }
+        if let Some(rel) = self.rel {
+            parts.push(format!("± {rel:e} relative"));
+        }
+        if let Some(ulps) = self.ulps {
+            parts.push(format!("± {ulps} ULPs"));
+        }
+        f.write_str(&parts.join(" or "))
+    }
+}
+
+/// The number of representable floats between `a` and `b`, or `None` if one of
+/// them is NaN.
+fn ulps_distance(a: f64, b: f64) -> Option<u64> {
+    /// Map the floats to integers with the same order.
+    fn ordered(float: f64) -> i128 {
+        #[expect(clippy::cast_possible_wrap, reason = "the sign bit is handled below")]
+        let bits = float.to_bits() as i64;
+        if bits < 0 {
+            i128::from(i64::MIN) - i128::from(bits)
+        } else {
+            i128::from(bits)
+        }
+    }
+
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    let distance = (ordered(a) - ordered(b)).unsigned_abs();
+    Some(u64::try_from(distance).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ErrorKind, Validator};
+
+    #[test]
+    fn abs() {
+        let validator = super::approx(2.3).abs(1e-9);
+
+        assert_eq!(Ok(()), validator.validate(&json!(2.3)));
+        assert_eq!(Ok(()), validator.validate(&json!(2.300_000_000_5)));
+        assert!(validator.validate(&json!(2.300_000_01)).is_err());
+    }
+
+    #[test]
+    fn rel() {
+        let validator = super::approx_rel(1e10, 1e-6);
+
+        assert_eq!(Ok(()), validator.validate(&json!(1e10 + 1000.0)));
+        assert!(validator.validate(&json!(1e10 + 100_000.0)).is_err());
+    }
+
+    #[test]
+    fn ulps() {
+        let validator = super::approx(0.3).ulps(2);
+
+        assert_eq!(Ok(()), validator.validate(&json!(0.1 + 0.2)));
+        assert!(validator.validate(&json!(0.300_000_001)).is_err());
+        assert_eq!(Some(1), super::ulps_distance(-0.0, f64::from_bits(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid absolute tolerance -1")]
+    fn negative_tolerance() {
+        let _unused = super::approx(1.0).abs(-1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid relative tolerance NaN")]
+    fn nan_tolerance() {
+        let _unused = super::approx_rel(1.0, f64::NAN);
+    }
+
+    #[test]
+    fn default_tolerance() {
+        let validator = super::approx(0.3);
+
+        assert_eq!(Ok(()), validator.validate(&json!(0.1 + 0.2)));
+        assert_eq!(Ok(()), validator.validate(&json!(0.3)));
+        assert!(validator.validate(&json!(0.31)).is_err());
+    }
+
+    #[test]
+    fn integer() {
+        assert_eq!(Ok(()), super::approx(5.0).validate(&json!(5)));
+    }
+
+    #[test]
+    fn any_tolerance() {
+        let validator = super::approx(100.0).abs(0.5).rel(0.01);
+
+        assert_eq!(Ok(()), validator.validate(&json!(100.9)));
+        assert!(validator.validate(&json!(101.1)).is_err());
+    }
+
+    #[test]
+    fn error() {
+        let validator = super::approx(2.3).abs(1e-9).rel(1e-6);
+
+        let value = json!(2.31);
+        let error = validator.validate(&value).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::NotApproxEqual { .. }));
+        assert_eq!(
+            "Invalid value. Expected 2.3 ± 1e-9 or ± 1e-6 relative but got 2.31. \
+            The difference is 1.00e-2 (> 1e-9), 4.33e-3 relative (> 1e-6).",
+            error.to_string()
+        );
+        assert!(matches!(
+            validator.validate(&json!("2.3")).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+}
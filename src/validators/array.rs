@@ -1,6 +1,6 @@
-use std::collections::HashSet;
+use std::ops::{Bound, RangeBounds};
 
-use crate::{validators, Error, Validator, Value};
+use crate::{collect_errors, validators, Error, ErrorKind, JsonType, Validator, Value};
 
 /// Match each array element to a specific validator.
 #[must_use]
@@ -31,31 +31,151 @@ struct ArrayValidator {
     validators: Vec<Box<dyn Validator>>,
 }
 
+impl ArrayValidator {
+    fn validate_size<'a>(&self, value: &'a Value) -> Result<&'a Vec<Value>, Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Array)))?;
+
+        let len = self.validators.len();
+        if value_vec.len() != len {
+            return Err(Error::new(
+                value,
+                ErrorKind::InvalidLength {
+                    expected: CountRange::new(&(len..=len)).describe("element"),
+                    actual: value_vec.len(),
+                },
+            ));
+        }
+
+        Ok(value_vec)
+    }
+}
+
 impl Validator for ArrayValidator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_size(value)?
+            .iter()
+            .zip(self.validators.iter())
+            .enumerate()
+            .try_for_each(|(index, (val, validator))| {
+                validator
+                    .validate(val)
+                    .map_err(|error| error.at_index(index))
+            })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        let value_vec = self.validate_size(value).map_err(|error| vec![error])?;
+
+        collect_errors(
+            value_vec
+                .iter()
+                .zip(self.validators.iter())
+                .enumerate()
+                .map(|(index, (val, validator))| at_index(validator.validate_all(val), index)),
+        )
+    }
+}
+
+/// Match the first elements of an array, ignoring the remaining ones.
+#[must_use]
+pub fn array_prefix(prefix: Vec<Box<dyn Validator>>) -> impl Validator {
+    array_prefix_suffix(prefix, vec![])
+}
+
+/// Match the last elements of an array, ignoring the preceding ones.
+#[must_use]
+pub fn array_suffix(suffix: Vec<Box<dyn Validator>>) -> impl Validator {
+    array_prefix_suffix(vec![], suffix)
+}
+
+/// Match the first and the last elements of an array, ignoring the elements
+/// in between.
+#[must_use]
+pub fn array_prefix_suffix(
+    prefix: Vec<Box<dyn Validator>>,
+    suffix: Vec<Box<dyn Validator>>,
+) -> impl Validator {
+    ArrayPrefixSuffixValidator { prefix, suffix }
+}
+
+struct ArrayPrefixSuffixValidator {
+    prefix: Vec<Box<dyn Validator>>,
+    suffix: Vec<Box<dyn Validator>>,
+}
+
+impl ArrayPrefixSuffixValidator {
+    /// Pair each validator with the index of the element it validates.
+    fn indexed_validators<'s, 'a>(
+        &'s self,
+        value: &'a Value,
+    ) -> Result<Vec<(usize, &'a Value, &'s dyn Validator)>, Error<'a>> {
         let value_vec = value
             .as_array()
-            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Array)))?;
 
-        if value_vec.len() != self.validators.len() {
-            return Err(Error::InvalidValue(
+        let min_len = self.prefix.len() + self.suffix.len();
+        if value_vec.len() < min_len {
+            return Err(Error::new(
                 value,
-                format!(
-                    "expected {} elements got {}",
-                    self.validators.len(),
-                    value_vec.len()
-                ),
+                ErrorKind::InvalidLength {
+                    expected: CountRange::new(&(min_len..)).describe("element"),
+                    actual: value_vec.len(),
+                },
             ));
         }
 
-        value_vec
+        let suffix_start = value_vec.len() - self.suffix.len();
+        Ok(self
+            .prefix
             .iter()
-            .zip(self.validators.iter())
-            .try_for_each(|(val, validator)| validator.validate(val))
+            .enumerate()
+            .chain(
+                self.suffix
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, validator)| (suffix_start + index, validator)),
+            )
+            .map(|(index, validator)| (index, &value_vec[index], validator.as_ref()))
+            .collect())
     }
 }
 
+impl Validator for ArrayPrefixSuffixValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.indexed_validators(value)?
+            .into_iter()
+            .try_for_each(|(index, val, validator)| {
+                validator
+                    .validate(val)
+                    .map_err(|error| error.at_index(index))
+            })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        collect_errors(
+            self.indexed_validators(value)
+                .map_err(|error| vec![error])?
+                .into_iter()
+                .map(|(index, val, validator)| at_index(validator.validate_all(val), index)),
+        )
+    }
+}
+
+fn at_index(result: Result<(), Vec<Error<'_>>>, index: usize) -> Result<(), Vec<Error<'_>>> {
+    result.map_err(|errors| {
+        errors
+            .into_iter()
+            .map(|error| error.at_index(index))
+            .collect()
+    })
+}
+
 /// Each supplied validator matches a different array element, in any order.
+///
+/// The validators are assigned to the elements with a maximum bipartite matching
+/// so the order of the validators doesn't matter.
 #[must_use]
 pub fn array_contains(validators: Vec<Box<dyn Validator>>) -> impl Validator {
     UnorderedArrayValidator { validators }
@@ -67,24 +187,75 @@ struct UnorderedArrayValidator {
 
 impl Validator for UnorderedArrayValidator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_all(value)
+            .map_err(|errors| errors.into_iter().next().unwrap())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
         let value_vec = value
             .as_array()
-            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
-        let mut matched_values: HashSet<usize> = HashSet::new();
-        for (m, validator) in self.validators.iter().enumerate() {
-            if let Some((n, _)) = value_vec
-                .iter()
-                .enumerate()
-                .filter(|(n, _)| !matched_values.contains(n))
-                .find(|(_, v)| validator.validate(v).is_ok())
-            {
-                matched_values.insert(n);
-            } else {
-                return Err(Error::UnmatchedValidator(value, m));
+            .ok_or_else(|| vec![Error::new(value, ErrorKind::InvalidType(JsonType::Array))])?;
+
+        // Elements accepted by each validator.
+        let candidates: Vec<Vec<usize>> = self
+            .validators
+            .iter()
+            .map(|validator| {
+                value_vec
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, val)| validator.validate(val).is_ok())
+                    .map(|(n, _)| n)
+                    .collect()
+            })
+            .collect();
+
+        let matching = maximum_matching(&candidates, value_vec.len());
+        let errors: Vec<Error<'a>> = candidates
+            .into_iter()
+            .enumerate()
+            .filter(|(m, _)| !matching.contains(&Some(*m)))
+            .map(|(m, candidates)| Error::new(value, ErrorKind::UnmatchedValidator(m, candidates)))
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Find a maximum matching between validators and elements with the augmenting
+/// path algorithm.
+///
+/// Returns, for each element, the index of the validator it is matched with.
+fn maximum_matching(candidates: &[Vec<usize>], elements_len: usize) -> Vec<Option<usize>> {
+    fn augment(
+        m: usize,
+        candidates: &[Vec<usize>],
+        visited: &mut [bool],
+        matching: &mut [Option<usize>],
+    ) -> bool {
+        for &n in &candidates[m] {
+            if visited[n] {
+                continue;
+            }
+            visited[n] = true;
+            if matching[n].is_none_or(|other| augment(other, candidates, visited, matching)) {
+                matching[n] = Some(m);
+                return true;
             }
         }
-        Ok(())
+        false
+    }
+
+    let mut matching = vec![None; elements_len];
+    for m in 0..candidates.len() {
+        let mut visited = vec![false; elements_len];
+        augment(m, candidates, &mut visited, &mut matching);
     }
+    matching
 }
 
 /// Match if each element match the validator
@@ -106,25 +277,194 @@ where
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
         let value_vec = value
             .as_array()
-            .ok_or_else(|| Error::InvalidType(value, String::from("array")))?;
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Array)))?;
 
-        value_vec
+        value_vec.iter().enumerate().try_for_each(|(index, val)| {
+            self.validator
+                .validate(val)
+                .map_err(|error| error.at_index(index))
+        })
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| vec![Error::new(value, ErrorKind::InvalidType(JsonType::Array))])?;
+
+        collect_errors(
+            value_vec
+                .iter()
+                .enumerate()
+                .map(|(index, val)| at_index(self.validator.validate_all(val), index)),
+        )
+    }
+}
+
+/// Match an array whose length is inside `range`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!("[1, 2, 3]", validators::array_len(1..=10));
+/// ```
+pub fn array_len(range: impl RangeBounds<usize>) -> impl Validator {
+    ArrayLenValidator {
+        range: CountRange::new(&range),
+    }
+}
+
+/// Match an array with at least one element.
+#[must_use]
+pub fn array_non_empty() -> impl Validator {
+    array_len(1..)
+}
+
+struct ArrayLenValidator {
+    range: CountRange,
+}
+
+impl Validator for ArrayLenValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Array)))?;
+
+        if self.range.contains(value_vec.len()) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                value,
+                ErrorKind::InvalidLength {
+                    expected: self.range.describe("element"),
+                    actual: value_vec.len(),
+                },
+            ))
+        }
+    }
+}
+
+/// Match an array with a number of elements matching `validator` inside
+/// `range`.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// // At least two elements are greater than 10.
+/// assert_json!("[5, 12, 20]", validators::array_count(validators::gt(10), 2..));
+/// ```
+pub fn array_count(validator: impl Validator, range: impl RangeBounds<usize>) -> impl Validator {
+    ArrayCountValidator {
+        validator,
+        range: CountRange::new(&range),
+    }
+}
+
+/// Match an array with at least one element matching `validator`.
+pub fn array_any(validator: impl Validator) -> impl Validator {
+    array_count(validator, 1..)
+}
+
+/// Match an array without any element matching `validator`.
+pub fn array_none(validator: impl Validator) -> impl Validator {
+    array_count(validator, ..=0)
+}
+
+struct ArrayCountValidator<T> {
+    validator: T,
+    range: CountRange,
+}
+
+impl<T> Validator for ArrayCountValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let value_vec = value
+            .as_array()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::Array)))?;
+
+        let matching: Vec<usize> = value_vec
             .iter()
-            .try_for_each(|val| self.validator.validate(val))
+            .enumerate()
+            .filter(|(_, val)| self.validator.validate(val).is_ok())
+            .map(|(n, _)| n)
+            .collect();
+
+        if self.range.contains(matching.len()) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                value,
+                ErrorKind::UnexpectedMatchCount {
+                    expected: self.range.describe("element"),
+                    matching,
+                },
+            ))
+        }
+    }
+}
+
+/// Inclusive range of a number of elements.
+#[derive(Debug, Clone, Copy)]
+struct CountRange {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl CountRange {
+    fn new(range: &impl RangeBounds<usize>) -> CountRange {
+        let min = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&end) => Some(end),
+            // An empty range, nothing is inside.
+            Bound::Excluded(&0) => {
+                return CountRange {
+                    min: 1,
+                    max: Some(0),
+                }
+            }
+            Bound::Excluded(&end) => Some(end - 1),
+            Bound::Unbounded => None,
+        };
+        CountRange { min, max }
+    }
+
+    fn contains(self, count: usize) -> bool {
+        self.min <= count && self.max.is_none_or(|max| count <= max)
+    }
+
+    /// Describe the range as a number of `noun`, like "at least 2 elements".
+    fn describe(self, noun: &str) -> String {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        match (self.min, self.max) {
+            (min, Some(max)) if min > max => format!("an impossible number of {noun}s"),
+            (_, Some(0)) => format!("no {noun}"),
+            (min, None) => format!("at least {min} {noun}{}", plural(min)),
+            (0, Some(max)) => format!("at most {max} {noun}{}", plural(max)),
+            (min, Some(max)) if min == max => format!("exactly {min} {noun}{}", plural(min)),
+            (min, Some(max)) => format!("between {min} and {max} {noun}s"),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{validators, Error, Validator};
+    use crate::{validators, Error, ErrorKind, Validator};
 
     #[test]
     fn non_array() {
         let validator = super::array(vec![]);
 
         assert!(matches!(
-            validator.validate(&serde_json::json!(null)),
-            Err(Error::InvalidType(_, _))
+            validator
+                .validate(&serde_json::json!(null))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidType(_),
         ));
     }
 
@@ -149,10 +489,20 @@ mod tests {
     fn different_size() {
         let validator = super::array(vec![]);
 
-        assert!(matches!(
-            validator.validate(&serde_json::json!([null])),
-            Err(Error::InvalidValue(_, _))
-        ));
+        assert_eq!(
+            "Invalid length. Expected no element but got 1.",
+            validator
+                .validate(&serde_json::json!([null]))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "Invalid length. Expected exactly 1 element but got 2.",
+            super::array_size(1)
+                .validate(&serde_json::json!([1, 2]))
+                .unwrap_err()
+                .to_string()
+        );
     }
 
     #[test]
@@ -160,8 +510,11 @@ mod tests {
         let validator = super::array(vec![Box::new(validators::null())]);
 
         assert!(matches!(
-            validator.validate(&serde_json::json!([5])),
-            Err(Error::InvalidType(_, _))
+            validator
+                .validate(&serde_json::json!([5]))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidType(_),
         ));
     }
 
@@ -175,6 +528,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn array_prefix() {
+        let validator = super::array_prefix(vec![Box::new(validators::eq(1))]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 2, 3])));
+        assert_eq!(
+            "Invalid length. Expected at least 1 element but got 0.",
+            validator
+                .validate(&serde_json::json!([]))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn array_suffix_error_path() {
+        let validator = super::array_suffix(vec![
+            Box::new(validators::eq(2)),
+            Box::new(validators::eq(3)),
+        ]);
+
+        let value = serde_json::json!([1, 2, 4]);
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!("/2", error.path().to_string());
+        assert_eq!(&serde_json::json!(4), error.location());
+    }
+
+    #[test]
+    fn array_prefix_suffix() {
+        let validator = super::array_prefix_suffix(
+            vec![Box::new(validators::eq(1))],
+            vec![Box::new(validators::eq(1))],
+        );
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 1])));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 5, 1])));
+        assert_eq!(
+            "Invalid length. Expected at least 2 elements but got 1.",
+            validator
+                .validate(&serde_json::json!([1]))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
     #[test]
     fn array_contains() {
         let validator = validators::array_contains(vec![
@@ -193,11 +591,43 @@ mod tests {
         ]);
 
         assert!(matches!(
-            validator.validate(&serde_json::json!([3, 1])),
-            Err(Error::UnmatchedValidator(_, _)),
+            validator
+                .validate(&serde_json::json!([3, 1]))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnmatchedValidator(_, _),
         ));
     }
 
+    #[test]
+    fn array_contains_not_greedy() {
+        let validator = validators::array_contains(vec![
+            Box::new(validators::any()),
+            Box::new(validators::eq(1)),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 2])));
+    }
+
+    #[test]
+    fn array_contains_unmatched_candidates() {
+        let validator = validators::array_contains(vec![
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+        ]);
+
+        let value = serde_json::json!([3, 1]);
+        let errors = validator.validate_all(&value).unwrap_err();
+        assert_eq!(
+            vec![
+                &ErrorKind::UnmatchedValidator(1, vec![1]),
+                &ErrorKind::UnmatchedValidator(2, vec![]),
+            ],
+            errors.iter().map(Error::kind).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn array_does_not_contain() {
         let validator = validators::array_contains(vec![
@@ -206,11 +636,51 @@ mod tests {
         ]);
 
         assert!(matches!(
-            validator.validate(&serde_json::json!([3, 1])),
-            Err(Error::UnmatchedValidator(_, _)),
+            validator
+                .validate(&serde_json::json!([3, 1]))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnmatchedValidator(_, _),
         ));
     }
 
+    #[test]
+    fn all_errors() {
+        let validator = super::array(vec![
+            Box::new(validators::null()),
+            Box::new(validators::eq(1)),
+            Box::new(validators::eq(2)),
+        ]);
+
+        let value = serde_json::json!([null, 2, 1]);
+        let errors = validator.validate_all(&value).unwrap_err();
+        assert_eq!(2, errors.len());
+        assert!(errors
+            .iter()
+            .all(|error| matches!(error.kind(), ErrorKind::NotEqual { .. })));
+    }
+
+    #[test]
+    fn error_path() {
+        let validator = super::array(vec![
+            Box::new(validators::null()),
+            Box::new(super::array_for_each(validators::null())),
+        ]);
+
+        let value = serde_json::json!([null, [null, 5]]);
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!("/1/1", error.path().to_string());
+    }
+
+    #[test]
+    fn for_each_all_errors() {
+        let validator = super::array_for_each(validators::null());
+
+        let value = serde_json::json!([1, null, "str"]);
+        let errors = validator.validate_all(&value).unwrap_err();
+        assert_eq!(2, errors.len());
+    }
+
     #[test]
     fn for_each() {
         let validator = super::array_for_each(validators::eq(String::from("test")));
@@ -220,4 +690,98 @@ mod tests {
             validator.validate(&serde_json::json!(["test", "test", "test"]))
         );
     }
+
+    #[test]
+    fn array_len() {
+        let validator = super::array_len(1..=2);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1])));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([1, 2])));
+        assert_eq!(
+            "Invalid length. Expected between 1 and 2 elements but got 3.",
+            validator
+                .validate(&serde_json::json!([1, 2, 3]))
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(matches!(
+            validator
+                .validate(&serde_json::json!({}))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidType(_),
+        ));
+        assert!(super::array_len(..100)
+            .validate(&serde_json::json!([]))
+            .is_ok());
+        assert!(super::array_len(..0)
+            .validate(&serde_json::json!([]))
+            .is_err());
+    }
+
+    #[test]
+    fn array_non_empty() {
+        let validator = super::array_non_empty();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([null])));
+        assert_eq!(
+            "Invalid length. Expected at least 1 element but got 0.",
+            validator
+                .validate(&serde_json::json!([]))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn array_count() {
+        let validator = super::array_count(validators::gt(10), 2..);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!([5, 12, 20])));
+        assert_eq!(
+            "Invalid number of matches. Expected at least 2 elements to match but element 1 matched.",
+            validator
+                .validate(&serde_json::json!([5, 12, 3]))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            &ErrorKind::UnexpectedMatchCount {
+                expected: String::from("exactly 1 element"),
+                matching: vec![0, 2],
+            },
+            super::array_count(validators::null(), 1..=1)
+                .validate(&serde_json::json!([null, 1, null]))
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn array_any_none() {
+        let value = serde_json::json!([1, null, 2, null]);
+
+        assert_eq!(
+            Ok(()),
+            super::array_any(validators::null()).validate(&value)
+        );
+        assert_eq!(
+            "Invalid number of matches. Expected at least 1 element to match but none matched.",
+            super::array_any(validators::eq(3))
+                .validate(&value)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            Ok(()),
+            super::array_none(validators::eq(3)).validate(&value)
+        );
+        assert_eq!(
+            "Invalid number of matches. Expected no element to match but elements 1, 3 matched.",
+            super::array_none(validators::null())
+                .validate(&value)
+                .unwrap_err()
+                .to_string()
+        );
+    }
 }
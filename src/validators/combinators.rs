@@ -0,0 +1,185 @@
+use crate::{collect_errors, Error, ErrorKind, Validator, Value};
+
+/// Match if at least one of the validators matches.
+///
+/// If none of them matches, the error of each validator is reported as a cause.
+#[must_use]
+pub fn any_of(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    AnyOfValidator { validators }
+}
+
+struct AnyOfValidator {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AnyOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let mut causes = vec![];
+        for validator in &self.validators {
+            match validator.validate(value) {
+                Ok(()) => return Ok(()),
+                Err(error) => causes.push(error),
+            }
+        }
+
+        Err(Error::new(value, ErrorKind::NoneMatched(self.validators.len())).with_causes(causes))
+    }
+}
+
+/// Match if all the validators match.
+#[must_use]
+pub fn all_of(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    AllOfValidator { validators }
+}
+
+struct AllOfValidator {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for AllOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        collect_errors(
+            self.validators
+                .iter()
+                .map(|validator| validator.validate_all(value)),
+        )
+    }
+}
+
+/// Match if exactly one of the validators matches.
+#[must_use]
+pub fn one_of(validators: Vec<Box<dyn Validator>>) -> impl Validator {
+    OneOfValidator { validators }
+}
+
+struct OneOfValidator {
+    validators: Vec<Box<dyn Validator>>,
+}
+
+impl Validator for OneOfValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let mut matched = vec![];
+        let mut causes = vec![];
+        for (index, validator) in self.validators.iter().enumerate() {
+            match validator.validate(value) {
+                Ok(()) => matched.push(index),
+                Err(error) => causes.push(error),
+            }
+        }
+
+        match matched.len() {
+            1 => Ok(()),
+            0 => Err(
+                Error::new(value, ErrorKind::NoneMatched(self.validators.len()))
+                    .with_causes(causes),
+            ),
+            _ => Err(Error::new(value, ErrorKind::MultipleMatched(matched))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validators, ErrorKind, Validator};
+
+    #[test]
+    fn any_of() {
+        let validator = super::any_of(vec![
+            Box::new(validators::null()),
+            Box::new(validators::eq(5)),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(null)));
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+    }
+
+    #[test]
+    fn any_of_none_matched() {
+        let validator = super::any_of(vec![
+            Box::new(validators::null()),
+            Box::new(validators::eq(5)),
+        ]);
+
+        let value = serde_json::json!(6);
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!(&ErrorKind::NoneMatched(2), error.kind());
+        assert_eq!(2, error.causes().len());
+        assert!(matches!(
+            error.causes()[0].kind(),
+            ErrorKind::InvalidType(_)
+        ));
+        assert!(matches!(
+            error.causes()[1].kind(),
+            ErrorKind::NotEqual { .. }
+        ));
+    }
+
+    #[test]
+    fn all_of() {
+        let validator = super::all_of(vec![
+            Box::new(validators::i64(|_| Ok(()))),
+            Box::new(validators::eq(5)),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert!(validator.validate(&serde_json::json!(6)).is_err());
+    }
+
+    #[test]
+    fn one_of() {
+        let validator = super::one_of(vec![
+            Box::new(validators::i64(|_| Ok(()))),
+            Box::new(validators::eq(5)),
+        ]);
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(6)));
+        assert_eq!(
+            &ErrorKind::MultipleMatched(vec![0, 1]),
+            validator
+                .validate(&serde_json::json!(5))
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(
+            &ErrorKind::NoneMatched(2),
+            validator
+                .validate(&serde_json::json!(null))
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn or() {
+        let validator = validators::null().or(validators::eq(5));
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!(5)));
+        assert_eq!(
+            &ErrorKind::NoneMatched(2),
+            validator
+                .validate(&serde_json::json!(6))
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn not() {
+        let validator = validators::eq("").not();
+
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!("text")));
+        assert_eq!(
+            &ErrorKind::UnexpectedMatch,
+            validator
+                .validate(&serde_json::json!(""))
+                .unwrap_err()
+                .kind()
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use crate::base64::{self, Alphabet};
+use crate::{collect_errors, Error, ErrorKind, JsonType, OwnedError, Validator, Value};
+
+/// Match a string containing a JSON document matching `validator`.
+///
+/// Use [`json_validator`!](crate::json_validator) to write `validator` with
+/// the syntax of [`assert_json`!](crate::assert_json). The errors inside the
+/// document are displayed in a nested diagnostic, with paths like
+/// `/payload#/id`.
+///
+/// ```
+/// use assert_json::{assert_json, json_validator, validators};
+///
+/// assert_json!(r#"{ "payload": "{\"id\": 1}" }"#, {
+///     "payload": validators::json_string(json_validator!({ "id": 1 })),
+/// });
+/// ```
+pub fn json_string(validator: impl Validator) -> impl Validator {
+    JsonStringValidator { validator }
+}
+
+struct JsonStringValidator<T> {
+    validator: T,
+}
+
+impl<T> Validator for JsonStringValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let string = value
+            .as_str()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::String)))?;
+        let document: Value = serde_json::from_str(string).map_err(|error| {
+            Error::new(
+                value,
+                ErrorKind::InvalidValue(format!("a string containing JSON ({error})")),
+            )
+        })?;
+
+        validate_embedded(value, document, &self.validator)
+    }
+}
+
+/// Match a string containing a JSON Web Token whose header matches
+/// `header_validator` and whose claims match `claims_validator`.
+///
+/// The signature is not verified. The errors are displayed in a nested
+/// diagnostic of the decoded token, with paths like `/token#/claims/sub`.
+///
+/// ```
+/// use assert_json::{assert_json, json_validator, validators};
+///
+/// let token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+///     eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+///     SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+/// assert_json!(serde_json::json!({ "token": token }), {
+///     "token": validators::jwt(
+///         json_validator!({ "alg": "HS256" }),
+///         json_validator!({ "sub": "1234567890" }),
+///     ),
+/// });
+/// ```
+pub fn jwt(header_validator: impl Validator, claims_validator: impl Validator) -> impl Validator {
+    JwtValidator {
+        decoded: DecodedJwtValidator {
+            header: header_validator,
+            claims: claims_validator,
+        },
+    }
+}
+
+struct JwtValidator<H, C> {
+    decoded: DecodedJwtValidator<H, C>,
+}
+
+impl<H, C> Validator for JwtValidator<H, C>
+where
+    H: Validator,
+    C: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let token = value
+            .as_str()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::String)))?;
+        let document = decode_jwt(token).map_err(|reason| {
+            Error::new(
+                value,
+                ErrorKind::InvalidValue(format!("a JSON Web Token ({reason})")),
+            )
+        })?;
+
+        validate_embedded(value, document, &self.decoded)
+    }
+}
+
+/// Validates a decoded token, an object with its `header` and its `claims`.
+struct DecodedJwtValidator<H, C> {
+    header: H,
+    claims: C,
+}
+
+impl<H, C> Validator for DecodedJwtValidator<H, C>
+where
+    H: Validator,
+    C: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_all(value)
+            .map_err(|errors| errors.into_iter().next().unwrap())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        collect_errors([
+            validate_part(value, "header", &self.header),
+            validate_part(value, "claims", &self.claims),
+        ])
+    }
+}
+
+fn validate_part<'a>(
+    document: &'a Value,
+    key: &str,
+    validator: &dyn Validator,
+) -> Result<(), Vec<Error<'a>>> {
+    validator
+        .validate_all(&document[key])
+        .map_err(|errors| errors.into_iter().map(|error| error.at_key(key)).collect())
+}
+
+/// Decode `token` into an object with its `header` and its `claims`.
+fn decode_jwt(token: &str) -> Result<Value, String> {
+    let segments: Vec<_> = token.split('.').collect();
+    let [header, claims, signature] = segments[..] else {
+        return Err(format!(
+            "expected 3 segments separated by '.', got {}",
+            segments.len()
+        ));
+    };
+
+    let decode = |segment: &str, name: &str| -> Result<Value, String> {
+        let bytes = base64::decode(segment, Alphabet::UrlSafeNoPad)
+            .map_err(|reason| format!("invalid base64url {name}: {reason}"))?;
+        serde_json::from_slice(&bytes).map_err(|error| format!("invalid JSON {name}: {error}"))
+    };
+    let header = decode(header, "header")?;
+    let claims = decode(claims, "claims")?;
+    base64::decode(signature, Alphabet::UrlSafeNoPad)
+        .map_err(|reason| format!("invalid base64url signature: {reason}"))?;
+
+    let mut document = serde_json::Map::new();
+    document.insert(String::from("header"), header);
+    document.insert(String::from("claims"), claims);
+    Ok(Value::Object(document))
+}
+
+/// Validate `document`, a JSON document embedded in `value`.
+fn validate_embedded<'a>(
+    value: &'a Value,
+    document: Value,
+    validator: &dyn Validator,
+) -> Result<(), Error<'a>> {
+    let errors: Vec<OwnedError> = match validator.validate_all(&document) {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors.into_iter().map(OwnedError::from).collect(),
+    };
+    Err(Error::new(
+        value,
+        ErrorKind::InvalidEmbeddedJson { document, errors },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{json_validator, validators, ErrorKind, Validator};
+
+    #[test]
+    fn json_string() {
+        let validator = super::json_string(json_validator!({ "id": 1 }));
+
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!(r#"{"id": 1, "name": "a"}"#))
+        );
+        assert!(matches!(
+            validator.validate(&json!({"id": 1})).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn json_string_invalid_document() {
+        let validator = json_validator!({
+            "payload": super::json_string(json_validator!({ "id": 1, "name": "a" })),
+        });
+
+        let value = json!({ "payload": r#"{"id": 2}"# });
+        let error = validator.validate(&value).unwrap_err();
+
+        let ErrorKind::InvalidEmbeddedJson { document, errors } = error.kind() else {
+            panic!("unexpected error kind: {:?}", error.kind());
+        };
+        assert_eq!(&json!({"id": 2}), document);
+        assert_eq!(2, errors.len());
+        assert_eq!(
+            "/payload: Invalid embedded JSON. #/id: Invalid value. Expected 1 but got 2. \
+            #: Missing key 'name' in object",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn json_string_invalid_json() {
+        let validator = super::json_string(validators::any());
+
+        assert_eq!(
+            "Invalid value. Expected a string containing JSON (EOF while parsing an object at \
+            line 1 column 1) but got \"{\".",
+            validator.validate(&json!("{")).unwrap_err().to_string()
+        );
+    }
+
+    const TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+        SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+
+    #[test]
+    fn jwt() {
+        let validator = super::jwt(
+            json_validator!({ "alg": "HS256", "typ": "JWT" }),
+            json_validator!({ "sub": "1234567890", "iat": 1_516_239_022 }),
+        );
+
+        assert_eq!(Ok(()), validator.validate(&json!(TOKEN)));
+        assert!(matches!(
+            validator.validate(&json!(5)).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn jwt_invalid_claims() {
+        let validator = super::jwt(
+            json_validator!({ "alg": "RS256" }),
+            json_validator!({ "name": "Jane Doe" }),
+        );
+
+        let value = json!(TOKEN);
+        let error = validator.validate(&value).unwrap_err();
+        let ErrorKind::InvalidEmbeddedJson { document, errors } = error.kind() else {
+            panic!("unexpected error kind: {:?}", error.kind());
+        };
+        assert_eq!(&json!("John Doe"), &document["claims"]["name"]);
+        let paths: Vec<_> = errors
+            .iter()
+            .map(|error| error.path().to_string())
+            .collect();
+        assert_eq!(vec!["/header/alg", "/claims/name"], paths);
+    }
+
+    #[test]
+    fn jwt_malformed() {
+        let validator = super::jwt(validators::any(), validators::any());
+
+        assert_eq!(
+            "Invalid value. Expected a JSON Web Token (expected 3 segments separated by '.', \
+            got 2) but got \"a.b\".",
+            validator.validate(&json!("a.b")).unwrap_err().to_string()
+        );
+        assert_eq!(
+            &ErrorKind::InvalidValue(String::from(
+                "a JSON Web Token (invalid base64url claims: invalid character '!' at position 1)"
+            )),
+            validator.validate(&json!("e30.e!30.")).unwrap_err().kind()
+        );
+        assert!(validator.validate(&json!("e30.bm90IGpzb24.")).is_err());
+        assert_eq!(Ok(()), validator.validate(&json!("e30.e30.")));
+    }
+}
@@ -0,0 +1,644 @@
+//! Validators of common string formats.
+//!
+//! Each validator matches a string in the given format. When the string is not
+//! valid, the error names the format and the reason the parsing failed.
+//!
+//! ```
+//! use assert_json::{assert_json, validators::format};
+//!
+//! assert_json!(r#"{
+//!     "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+//!     "created_at": "2024-02-29T13:37:00Z"
+//! }"#, {
+//!     "id": format::uuid(),
+//!     "created_at": format::date_time(),
+//! });
+//! ```
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::base64::{self, Alphabet};
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
+
+/// Match a UUID in its hyphenated form, like
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[must_use]
+pub fn uuid() -> impl Validator {
+    FormatValidator {
+        name: "a UUID",
+        parse: parse_uuid,
+    }
+}
+
+/// Match a version 4 (random) UUID in its hyphenated form.
+#[must_use]
+pub fn uuid_v4() -> impl Validator {
+    FormatValidator {
+        name: "a version 4 UUID",
+        parse: |string| {
+            parse_uuid(string)?;
+            let bytes = string.as_bytes();
+            if bytes[14] != b'4' {
+                return Err(format!("version is {} instead of 4", char::from(bytes[14])));
+            }
+            if !matches!(bytes[19], b'8' | b'9' | b'a' | b'b' | b'A' | b'B') {
+                return Err(format!(
+                    "variant digit '{}' is not one of 8, 9, a or b",
+                    char::from(bytes[19])
+                ));
+            }
+            Ok(())
+        },
+    }
+}
+
+/// Match an RFC 3339 date-time, like `2024-02-29T13:37:00.123+01:00`.
+#[must_use]
+pub fn date_time() -> impl Validator {
+    FormatValidator {
+        name: "an RFC 3339 date-time",
+        parse: |string| {
+            let (date, time) = string
+                .split_once(['T', 't'])
+                .ok_or("missing 'T' between date and time")?;
+            parse_date(date)?;
+            parse_time(time)
+        },
+    }
+}
+
+/// Match an RFC 3339 full-date, like `2024-02-29`.
+#[must_use]
+pub fn date() -> impl Validator {
+    FormatValidator {
+        name: "an RFC 3339 date",
+        parse: parse_date,
+    }
+}
+
+/// Match an email address, like `user@example.com`.
+///
+/// Only the common dot-atom form is accepted: quoted local parts and IP
+/// address domains are rejected.
+#[must_use]
+pub fn email() -> impl Validator {
+    FormatValidator {
+        name: "an email address",
+        parse: |string| {
+            let (local, domain) = string.rsplit_once('@').ok_or("missing '@'")?;
+            if local.is_empty() {
+                return Err(String::from("empty local part"));
+            }
+            if local.len() > 64 {
+                return Err(String::from("local part longer than 64 characters"));
+            }
+            if let Some(character) = local
+                .chars()
+                .find(|&c| !c.is_ascii_alphanumeric() && !"!#$%&'*+-/=?^_`{|}~.".contains(c))
+            {
+                return Err(format!("invalid character '{character}' in local part"));
+            }
+            if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+                return Err(String::from("misplaced '.' in local part"));
+            }
+            parse_hostname(domain).map_err(|reason| format!("invalid domain: {reason}"))
+        },
+    }
+}
+
+/// Match an absolute URI, like `https://example.com/path?query#fragment`.
+#[must_use]
+pub fn uri() -> impl Validator {
+    FormatValidator {
+        name: "a URI",
+        parse: |string| {
+            let (scheme, rest) = string.split_once(':').ok_or("missing scheme")?;
+            if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                || !scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+            {
+                return Err(format!("invalid scheme '{scheme}'"));
+            }
+
+            for (index, character) in rest.char_indices() {
+                let position = scheme.len() + 1 + index;
+                if character == '%' {
+                    let hex = rest.as_bytes().get(index + 1..index + 3);
+                    if !hex.is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)) {
+                        return Err(format!("invalid percent-encoding at position {position}"));
+                    }
+                } else if !character.is_ascii_alphanumeric()
+                    && !"-._~:/?#[]@!$&'()*+,;=".contains(character)
+                {
+                    return Err(format!(
+                        "invalid character '{}' at position {position}",
+                        character.escape_default()
+                    ));
+                }
+            }
+            Ok(())
+        },
+    }
+}
+
+/// Match an IPv4 address in dotted decimal notation, like `192.168.0.1`.
+#[must_use]
+pub fn ipv4() -> impl Validator {
+    FormatValidator {
+        name: "an IPv4 address",
+        parse: |string| {
+            string
+                .parse::<Ipv4Addr>()
+                .map(drop)
+                .map_err(|e| e.to_string())
+        },
+    }
+}
+
+/// Match an IPv6 address, like `2001:db8::1`.
+#[must_use]
+pub fn ipv6() -> impl Validator {
+    FormatValidator {
+        name: "an IPv6 address",
+        parse: |string| {
+            string
+                .parse::<Ipv6Addr>()
+                .map(drop)
+                .map_err(|e| e.to_string())
+        },
+    }
+}
+
+/// Match an RFC 1123 hostname, like `api.example.com`.
+#[must_use]
+pub fn hostname() -> impl Validator {
+    FormatValidator {
+        name: "a hostname",
+        parse: parse_hostname,
+    }
+}
+
+/// Match a base64 string using the standard alphabet with padding.
+#[must_use]
+pub fn base64() -> impl Validator {
+    FormatValidator {
+        name: "a base64 string",
+        parse: |string| base64::decode(string, Alphabet::Standard).map(drop),
+    }
+}
+
+/// Match a semantic version, like `1.0.0-alpha.1+build.5`.
+#[must_use]
+pub fn semver() -> impl Validator {
+    FormatValidator {
+        name: "a semantic version",
+        parse: |string| {
+            let (version, build) = match string.split_once('+') {
+                Some((version, build)) => (version, Some(build)),
+                None => (string, None),
+            };
+            let (version, pre_release) = match version.split_once('-') {
+                Some((version, pre_release)) => (version, Some(pre_release)),
+                None => (version, None),
+            };
+
+            let parts: Vec<_> = version.split('.').collect();
+            if parts.len() != 3 {
+                return Err(String::from("expected MAJOR.MINOR.PATCH"));
+            }
+            for (part, name) in parts.iter().zip(["major", "minor", "patch"]) {
+                parse_numeric_identifier(part)
+                    .map_err(|reason| format!("invalid {name} version: {reason}"))?;
+            }
+
+            if let Some(pre_release) = pre_release {
+                for identifier in pre_release.split('.') {
+                    parse_identifier(identifier)
+                        .map_err(|reason| format!("invalid pre-release: {reason}"))?;
+                    if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                        parse_numeric_identifier(identifier)
+                            .map_err(|reason| format!("invalid pre-release: {reason}"))?;
+                    }
+                }
+            }
+            if let Some(build) = build {
+                for identifier in build.split('.') {
+                    parse_identifier(identifier)
+                        .map_err(|reason| format!("invalid build metadata: {reason}"))?;
+                }
+            }
+            Ok(())
+        },
+    }
+}
+
+struct FormatValidator {
+    /// The format, as used in error messages.
+    name: &'static str,
+    /// Returns the reason why the string is not in the format.
+    parse: fn(&str) -> Result<(), String>,
+}
+
+impl Validator for FormatValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let string = value
+            .as_str()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::String)))?;
+
+        (self.parse)(string).map_err(|reason| {
+            Error::new(
+                value,
+                ErrorKind::InvalidValue(format!("{} ({reason})", self.name)),
+            )
+        })
+    }
+}
+
+fn parse_uuid(string: &str) -> Result<(), String> {
+    if string.len() != 36 {
+        return Err(format!("expected 36 characters, got {}", string.len()));
+    }
+    for (position, character) in string.char_indices() {
+        if matches!(position, 8 | 13 | 18 | 23) {
+            if character != '-' {
+                return Err(format!("expected '-' at position {position}"));
+            }
+        } else if !character.is_ascii_hexdigit() {
+            return Err(format!(
+                "invalid hexadecimal digit '{character}' at position {position}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parse a fixed size number of decimal digits.
+fn parse_digits(string: &str, name: &str) -> Result<u32, String> {
+    if string.is_empty() || !string.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("invalid {name} '{string}'"));
+    }
+    string
+        .parse()
+        .map_err(|_| format!("invalid {name} '{string}'"))
+}
+
+fn parse_date(string: &str) -> Result<(), String> {
+    let parts: Vec<_> = string.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return Err(String::from("expected YYYY-MM-DD"));
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(String::from("expected YYYY-MM-DD"));
+    }
+
+    let year = parse_digits(year, "year")?;
+    let month = parse_digits(month, "month")?;
+    let day = parse_digits(day, "day")?;
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return Err(format!("month {month} is not between 1 and 12")),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(format!(
+            "day {day} is not between 1 and {days_in_month} for {year}-{month:02}"
+        ));
+    }
+    Ok(())
+}
+
+fn parse_time(string: &str) -> Result<(), String> {
+    let (time, offset) = if let Some(time) = string.strip_suffix(['Z', 'z']) {
+        (time, None)
+    } else {
+        let index = string.rfind(['+', '-']).ok_or("missing time zone offset")?;
+        (&string[..index], Some(&string[index + 1..]))
+    };
+
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let parts: Vec<_> = time.split(':').collect();
+    let [hour, minute, second] = parts[..] else {
+        return Err(String::from("expected HH:MM:SS time"));
+    };
+    if hour.len() != 2 || minute.len() != 2 || second.len() != 2 {
+        return Err(String::from("expected HH:MM:SS time"));
+    }
+    if parse_digits(hour, "hour")? > 23 {
+        return Err(format!("hour {hour} is greater than 23"));
+    }
+    if parse_digits(minute, "minute")? > 59 {
+        return Err(format!("minute {minute} is greater than 59"));
+    }
+    // 60 is allowed for leap seconds.
+    if parse_digits(second, "second")? > 60 {
+        return Err(format!("second {second} is greater than 60"));
+    }
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("invalid fraction of second '{fraction}'"));
+        }
+    }
+
+    if let Some(offset) = offset {
+        let Some((hour, minute)) = offset.split_once(':') else {
+            return Err(String::from("expected time zone offset as HH:MM"));
+        };
+        if hour.len() != 2
+            || minute.len() != 2
+            || parse_digits(hour, "offset hour")? > 23
+            || parse_digits(minute, "offset minute")? > 59
+        {
+            return Err(format!("invalid time zone offset '{offset}'"));
+        }
+    }
+    Ok(())
+}
+
+fn parse_hostname(string: &str) -> Result<(), String> {
+    if string.is_empty() {
+        return Err(String::from("empty hostname"));
+    }
+    if string.len() > 253 {
+        return Err(String::from("longer than 253 characters"));
+    }
+    for label in string.split('.') {
+        if label.is_empty() {
+            return Err(String::from("empty label"));
+        }
+        if label.len() > 63 {
+            return Err(format!("label '{label}' longer than 63 characters"));
+        }
+        if let Some(character) = label
+            .chars()
+            .find(|&c| !c.is_ascii_alphanumeric() && c != '-')
+        {
+            return Err(format!(
+                "invalid character '{character}' in label '{label}'"
+            ));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(format!("label '{label}' starts or ends with '-'"));
+        }
+    }
+    Ok(())
+}
+
+/// Parse a semantic version identifier.
+fn parse_identifier(identifier: &str) -> Result<(), String> {
+    if identifier.is_empty() {
+        return Err(String::from("empty identifier"));
+    }
+    if let Some(character) = identifier
+        .chars()
+        .find(|&c| !c.is_ascii_alphanumeric() && c != '-')
+    {
+        return Err(format!(
+            "invalid character '{character}' in identifier '{identifier}'"
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a numeric semantic version identifier.
+fn parse_numeric_identifier(identifier: &str) -> Result<(), String> {
+    if identifier.is_empty() || !identifier.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("'{identifier}' is not a number"));
+    }
+    if identifier.len() > 1 && identifier.starts_with('0') {
+        return Err(format!("'{identifier}' has a leading zero"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ErrorKind, Validator};
+
+    fn assert_valid(validator: &dyn Validator, strings: &[&str]) {
+        for string in strings {
+            assert_eq!(Ok(()), validator.validate(&json!(string)), "{string}");
+        }
+    }
+
+    fn assert_invalid(validator: &dyn Validator, strings: &[&str]) {
+        for string in strings {
+            assert!(validator.validate(&json!(string)).is_err(), "{string}");
+        }
+    }
+
+    #[test]
+    fn uuid() {
+        let validator = super::uuid();
+
+        assert_valid(
+            &validator,
+            &[
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                "67E55044-10B1-126F-0247-BB680E5FE0C8",
+            ],
+        );
+        assert_invalid(
+            &validator,
+            &[
+                "67e5504410b1426f9247bb680e5fe0c8",
+                "67e55044-10b1-426f-9247_bb680e5fe0c8",
+                "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            ],
+        );
+        assert_eq!(
+            "Invalid value. Expected a UUID (invalid hexadecimal digit 'g' at position 35) \
+            but got \"67e55044-10b1-426f-9247-bb680e5fe0cg\".",
+            validator
+                .validate(&json!("67e55044-10b1-426f-9247-bb680e5fe0cg"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(matches!(
+            validator.validate(&json!(5)).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn uuid_v4() {
+        let validator = super::uuid_v4();
+
+        assert_valid(&validator, &["67e55044-10b1-426f-9247-bb680e5fe0c8"]);
+        assert_invalid(
+            &validator,
+            &[
+                "67e55044-10b1-126f-9247-bb680e5fe0c8",
+                "67e55044-10b1-426f-c247-bb680e5fe0c8",
+            ],
+        );
+    }
+
+    #[test]
+    fn date_time() {
+        let validator = super::date_time();
+
+        assert_valid(
+            &validator,
+            &[
+                "2024-02-29T13:37:00Z",
+                "2024-02-29t13:37:00.123456z",
+                "1990-12-31T23:59:60-08:00",
+                "2024-01-01T00:00:00+01:30",
+            ],
+        );
+        assert_invalid(
+            &validator,
+            &[
+                "2024-02-29",
+                "2024-02-29T13:37:00",
+                "2024-02-29T24:00:00Z",
+                "2023-02-29T13:37:00Z",
+                "2024-02-29T13:37Z",
+                "2024-02-29T13:37:00.Z",
+                "2024-02-29T13:37:00+0100",
+            ],
+        );
+    }
+
+    #[test]
+    fn date() {
+        let validator = super::date();
+
+        assert_valid(&validator, &["2024-02-29", "2000-02-29", "1999-12-31"]);
+        assert_invalid(
+            &validator,
+            &[
+                "1900-02-29",
+                "2024-13-01",
+                "2024-04-31",
+                "2024-1-01",
+                "24-01-01",
+            ],
+        );
+        assert_eq!(
+            "Invalid value. Expected an RFC 3339 date (day 31 is not between 1 and 30 for \
+            2024-04) but got \"2024-04-31\".",
+            validator
+                .validate(&json!("2024-04-31"))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn email() {
+        let validator = super::email();
+
+        assert_valid(
+            &validator,
+            &["user@example.com", "first.last+tag@sub.example.org"],
+        );
+        assert_invalid(
+            &validator,
+            &[
+                "example.com",
+                "@example.com",
+                "user@",
+                ".user@example.com",
+                "us..er@example.com",
+                "us er@example.com",
+                "user@-example.com",
+            ],
+        );
+    }
+
+    #[test]
+    fn uri() {
+        let validator = super::uri();
+
+        assert_valid(
+            &validator,
+            &[
+                "https://example.com/path?query=1#fragment",
+                "urn:isbn:0451450523",
+                "mailto:user@example.com",
+                "https://example.com/a%20b",
+            ],
+        );
+        assert_invalid(
+            &validator,
+            &[
+                "/relative/path",
+                "1http://example.com",
+                "https://example.com/a b",
+                "https://example.com/%zz",
+                "https://example.com/%2",
+            ],
+        );
+    }
+
+    #[test]
+    fn ip() {
+        assert_valid(&super::ipv4(), &["192.168.0.1", "0.0.0.0"]);
+        assert_invalid(&super::ipv4(), &["256.0.0.1", "1.2.3", "::1"]);
+        assert_valid(
+            &super::ipv6(),
+            &["::1", "2001:db8::1", "::ffff:192.168.0.1"],
+        );
+        assert_invalid(&super::ipv6(), &["192.168.0.1", "2001:db8:::1", "g::1"]);
+    }
+
+    #[test]
+    fn hostname() {
+        let validator = super::hostname();
+
+        assert_valid(&validator, &["localhost", "api.example.com", "a-b.c0"]);
+        assert_invalid(
+            &validator,
+            &["", "-a.com", "a-.com", "a..com", "a_b.com", &"a".repeat(64)],
+        );
+    }
+
+    #[test]
+    fn base64() {
+        let validator = super::base64();
+
+        assert_valid(&validator, &["", "Zg==", "Zm9vYmFy", "+/8="]);
+        assert_invalid(&validator, &["Zg", "Zm9v!mFy", "Z==="]);
+    }
+
+    #[test]
+    fn semver() {
+        let validator = super::semver();
+
+        assert_valid(
+            &validator,
+            &[
+                "0.0.0",
+                "1.2.3",
+                "1.0.0-alpha.1",
+                "1.0.0-0.3.7",
+                "1.0.0-x-y-z.--",
+                "1.0.0+build.5",
+                "1.0.0-rc.1+sha.5114f85",
+            ],
+        );
+        assert_invalid(
+            &validator,
+            &[
+                "1.2",
+                "1.2.3.4",
+                "01.2.3",
+                "1.2.3-",
+                "1.2.3-01",
+                "1.2.3-alpha..1",
+                "1.2.3+",
+                "v1.2.3",
+            ],
+        );
+    }
+}
@@ -1,14 +1,29 @@
-use std::fmt::Debug;
-
-use crate::{get_value_type_id, Error, Validator, Value};
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
 
+mod approx;
 mod array;
+mod combinators;
+mod embedded;
+pub mod format;
+mod number;
 mod object;
+#[cfg(feature = "regex")]
+mod pattern;
 mod primitive;
+mod range;
+mod string;
 
+pub use approx::*;
 pub use array::*;
+pub use combinators::*;
+pub use embedded::*;
+pub use number::*;
 pub use object::*;
+#[cfg(feature = "regex")]
+pub use pattern::*;
 pub use primitive::*;
+pub use range::*;
+pub use string::*;
 
 /// Match any value.
 ///
@@ -27,44 +42,68 @@ impl Validator for AnyValidator {
 }
 
 /// Match a value equals the expected value.
+///
+/// Numbers are compared by mathematical value, so `5` matches `5.0`. Use
+/// [`eq_strict`] to also compare their representation.
 pub fn eq<T>(expected: T) -> impl Validator
 where
-    T: Into<Value> + Clone + Debug + 'static,
+    T: Into<Value>,
 {
-    EqValidator { expected }
+    eq_with(expected, NumberComparison::Value)
 }
 
-struct EqValidator<T>
+/// Match a value equals the expected value, with the numbers having the same
+/// representation.
+///
+/// Integers never match floats, so `5` doesn't match `5.0`.
+pub fn eq_strict<T>(expected: T) -> impl Validator
 where
-    T: Into<Value> + Clone + Debug,
+    T: Into<Value>,
 {
-    expected: T,
+    eq_with(expected, NumberComparison::Representation)
 }
 
-impl<T> Validator for EqValidator<T>
+/// Match a value equals the expected value, with the numbers compared
+/// according to `comparison`.
+pub fn eq_with<T>(expected: T, comparison: NumberComparison) -> impl Validator
 where
-    T: Into<Value> + Clone + Debug,
+    T: Into<Value>,
 {
+    EqValidator {
+        expected: expected.into(),
+        comparison,
+    }
+}
+
+struct EqValidator {
+    expected: Value,
+    comparison: NumberComparison,
+}
+
+impl Validator for EqValidator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
-        let expected_val = self.expected.clone().into();
-        if get_value_type_id(&expected_val) != get_value_type_id(value) {
-            return Err(Error::InvalidType(
-                value,
-                get_value_type_id(&expected_val).to_string(),
-            ));
+        let expected_type = JsonType::of(&self.expected);
+        if expected_type != JsonType::of(value) {
+            return Err(Error::new(value, ErrorKind::InvalidType(expected_type)));
         }
 
-        if value == &expected_val {
+        if values_equal(value, &self.expected, self.comparison) {
             Ok(())
         } else {
-            Err(Error::InvalidValue(value, format!("{:?}", self.expected)))
+            Err(Error::new(
+                value,
+                ErrorKind::NotEqual {
+                    expected: self.expected.clone(),
+                    actual: value.clone(),
+                },
+            ))
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Error, Validator, Value};
+    use crate::{ErrorKind, JsonType, Validator, Value};
 
     #[test]
     fn any() {
@@ -84,9 +123,52 @@ mod tests {
     fn eq_string_fail() {
         let validator = super::eq(String::from("test"));
 
+        assert_eq!(
+            &ErrorKind::NotEqual {
+                expected: serde_json::json!("test"),
+                actual: serde_json::json!("not expected"),
+            },
+            validator
+                .validate(&serde_json::json!("not expected"))
+                .unwrap_err()
+                .kind(),
+        );
+    }
+
+    #[test]
+    fn eq_number() {
+        assert_eq!(Ok(()), super::eq(5).validate(&serde_json::json!(5.0)));
+        assert_eq!(Ok(()), super::eq(5.0).validate(&serde_json::json!(5)));
+        assert_eq!(
+            Ok(()),
+            super::eq(serde_json::json!([1, {"a": 2}]))
+                .validate(&serde_json::json!([1.0, {"a": 2.0}]))
+        );
+        assert!(super::eq(5).validate(&serde_json::json!(5.5)).is_err());
+    }
+
+    #[test]
+    fn eq_strict_number() {
+        assert_eq!(Ok(()), super::eq_strict(5).validate(&serde_json::json!(5)));
         assert!(matches!(
-            validator.validate(&serde_json::json!("not expected")),
-            Err(Error::InvalidValue(_, _))
+            super::eq_strict(5)
+                .validate(&serde_json::json!(5.0))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NotEqual { .. }
         ));
     }
+
+    #[test]
+    fn eq_invalid_type() {
+        let validator = super::eq(5);
+
+        assert_eq!(
+            &ErrorKind::InvalidType(JsonType::Number),
+            validator
+                .validate(&serde_json::json!("5"))
+                .unwrap_err()
+                .kind(),
+        );
+    }
 }
@@ -0,0 +1,195 @@
+use serde_json::Number;
+
+use super::primitive::PrimitiveValidator;
+use crate::{JsonType, Validator, Value};
+
+/// Match if number match predicate.
+///
+/// Unlike [`i64`](super::i64), [`u64`](super::u64) and [`f64`](super::f64), any
+/// number is accepted whatever its representation.
+pub fn number<F>(predicate: F) -> impl Validator
+where
+    F: Fn(&Number) -> Result<(), String> + 'static,
+{
+    PrimitiveValidator {
+        json_type: JsonType::Number,
+        representation: "a number",
+        extract: |val| as_number(val).cloned(),
+        predicate,
+    }
+}
+
+/// Match if the number is an integer matching the predicate.
+///
+/// Integers are recognized by their mathematical value, so `5`, `-5` and `5.0`
+/// are all integers while `5.5` isn't.
+pub fn integer<F>(predicate: F) -> impl Validator
+where
+    F: Fn(&i128) -> Result<(), String> + 'static,
+{
+    PrimitiveValidator {
+        json_type: JsonType::Number,
+        representation: "an integer",
+        extract: |val| as_number(val).and_then(as_i128),
+        predicate,
+    }
+}
+
+/// How [`eq_with`](super::eq_with) compares numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumberComparison {
+    /// Compare the mathematical value of the numbers: `5`, `5.0` and `5e0`
+    /// are equal.
+    #[default]
+    Value,
+    /// Compare the representation of the numbers: integers are never equal
+    /// to floats, so `5` and `5.0` are different.
+    Representation,
+}
+
+fn as_number(value: &Value) -> Option<&Number> {
+    match value {
+        Value::Number(number) => Some(number),
+        _ => None,
+    }
+}
+
+/// The integer value of `number`, if it is an integer.
+pub(crate) fn as_i128(number: &Number) -> Option<i128> {
+    if let Some(int) = number.as_i64() {
+        return Some(int.into());
+    }
+    if let Some(int) = number.as_u64() {
+        return Some(int.into());
+    }
+
+    // The bounds of i128 are powers of two, so they are exact as f64.
+    let bound = 2_f64.powi(127);
+    let float = number.as_f64()?;
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "float is an integer in range"
+    )]
+    (float.fract() == 0.0 && (-bound..bound).contains(&float)).then_some(float as i128)
+}
+
+/// Compare two numbers by mathematical value.
+pub(crate) fn numbers_equal(a: &Number, b: &Number) -> bool {
+    match (as_i128(a), as_i128(b)) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => a.as_f64() == b.as_f64(),
+        _ => false,
+    }
+}
+
+/// Compare two values, with their numbers compared according to `comparison`.
+pub(crate) fn values_equal(a: &Value, b: &Value, comparison: NumberComparison) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => match comparison {
+            NumberComparison::Value => numbers_equal(a, b),
+            NumberComparison::Representation => a == b,
+        },
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| values_equal(a, b, comparison))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| values_equal(a, b, comparison)))
+        }
+        (a, b) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::NumberComparison;
+    use crate::{ErrorKind, Validator};
+
+    #[test]
+    fn number() {
+        let validator = super::number(|number| {
+            if number.is_f64() {
+                Ok(())
+            } else {
+                Err(String::from("a float"))
+            }
+        });
+
+        assert_eq!(Ok(()), validator.validate(&json!(5.0)));
+        assert!(matches!(
+            validator.validate(&json!(5)).unwrap_err().kind(),
+            ErrorKind::InvalidValue(_)
+        ));
+        assert!(matches!(
+            validator.validate(&json!("5")).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn integer() {
+        let validator = super::integer(|&int| {
+            if int > 0 {
+                Ok(())
+            } else {
+                Err(String::from("a positive integer"))
+            }
+        });
+
+        assert_eq!(Ok(()), validator.validate(&json!(5)));
+        assert_eq!(Ok(()), validator.validate(&json!(5.0)));
+        assert_eq!(Ok(()), validator.validate(&json!(u64::MAX)));
+        assert!(matches!(
+            validator.validate(&json!(-5)).unwrap_err().kind(),
+            ErrorKind::InvalidValue(_)
+        ));
+        assert_eq!(
+            "Invalid value. Expected an integer but got 5.5.",
+            validator.validate(&json!(5.5)).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn numbers_equal() {
+        let number = |value: serde_json::Value| value.as_number().unwrap().clone();
+
+        assert!(super::numbers_equal(&number(json!(5)), &number(json!(5.0))));
+        assert!(super::numbers_equal(
+            &number(json!(-5)),
+            &number(json!(-5.0))
+        ));
+        assert!(super::numbers_equal(
+            &number(json!(0.5)),
+            &number(json!(0.5))
+        ));
+        assert!(!super::numbers_equal(
+            &number(json!(5)),
+            &number(json!(5.5))
+        ));
+        assert!(!super::numbers_equal(
+            &number(json!(u64::MAX)),
+            &number(json!(u64::MAX - 1))
+        ));
+    }
+
+    #[test]
+    fn values_equal() {
+        let a = json!({"a": [1, 2.0], "b": {"c": -3}});
+        let b = json!({"a": [1.0, 2], "b": {"c": -3.0}});
+
+        assert!(super::values_equal(&a, &b, NumberComparison::Value));
+        assert!(!super::values_equal(
+            &a,
+            &b,
+            NumberComparison::Representation
+        ));
+        assert!(!super::values_equal(
+            &json!([1]),
+            &json!([1, 2]),
+            NumberComparison::Value
+        ));
+    }
+}
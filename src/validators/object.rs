@@ -1,24 +1,30 @@
-use std::collections::HashMap;
-
-use crate::{Error, Validator, Value};
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
 
 /// Match if each key/value pair matches
 ///
 /// Ignore key that are not specified. Use [`object_strict`] if you want to
 /// exactly match all the key/values.
+///
+/// The keys are validated, and their errors reported, in iteration order.
 #[must_use]
-pub fn object(key_validators: HashMap<String, Box<dyn Validator>>) -> impl Validator {
+pub fn object<I>(key_validators: I) -> impl Validator
+where
+    I: IntoIterator<Item = (String, Box<dyn Validator>)>,
+{
     ObjectValidator {
-        key_validators,
+        entries: required_entries(key_validators),
         strict: false,
     }
 }
 
 /// Match if each key/value pairs matches. Fail if a key is missing in the validators.
 #[must_use]
-pub fn object_strict(key_validators: HashMap<String, Box<dyn Validator>>) -> impl Validator {
+pub fn object_strict<I>(key_validators: I) -> impl Validator
+where
+    I: IntoIterator<Item = (String, Box<dyn Validator>)>,
+{
     ObjectValidator {
-        key_validators,
+        entries: required_entries(key_validators),
         strict: true,
     }
 }
@@ -27,42 +33,117 @@ pub fn object_strict(key_validators: HashMap<String, Box<dyn Validator>>) -> imp
 #[must_use]
 pub fn object_empty() -> impl Validator {
     ObjectValidator {
-        key_validators: HashMap::new(),
+        entries: vec![],
+        strict: true,
+    }
+}
+
+/// Match if each entry matches.
+///
+/// Like [`object`] but each key can be either required, optional or absent.
+#[must_use]
+pub fn object_entries<I>(entries: I) -> impl Validator
+where
+    I: IntoIterator<Item = (String, ObjectEntry)>,
+{
+    ObjectValidator {
+        entries: entries.into_iter().collect(),
+        strict: false,
+    }
+}
+
+/// Match if each entry matches. Fail if a key is missing in the entries.
+///
+/// Like [`object_strict`] but each key can be either required, optional or absent.
+#[must_use]
+pub fn object_entries_strict<I>(entries: I) -> impl Validator
+where
+    I: IntoIterator<Item = (String, ObjectEntry)>,
+{
+    ObjectValidator {
+        entries: entries.into_iter().collect(),
         strict: true,
     }
 }
 
+/// Expectation on a key of an object, used by [`object_entries`].
+pub enum ObjectEntry {
+    /// The key must be present and its value must match the validator.
+    Required(Box<dyn Validator>),
+    /// The value must match the validator only if the key is present.
+    Optional(Box<dyn Validator>),
+    /// The key must not be present.
+    Absent,
+}
+
+fn required_entries<I>(key_validators: I) -> Vec<(String, ObjectEntry)>
+where
+    I: IntoIterator<Item = (String, Box<dyn Validator>)>,
+{
+    key_validators
+        .into_iter()
+        .map(|(key, validator)| (key, ObjectEntry::Required(validator)))
+        .collect()
+}
+
 struct ObjectValidator {
-    key_validators: HashMap<String, Box<dyn Validator>>,
+    entries: Vec<(String, ObjectEntry)>,
     strict: bool,
 }
 
 impl Validator for ObjectValidator {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_all(value)
+            .map_err(|errors| errors.into_iter().next().unwrap())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
         let object = value
             .as_object()
-            .ok_or_else(|| Error::InvalidType(value, String::from("object")))?;
-
-        for (key, validator) in &self.key_validators {
-            let inner_value = object
-                .get(key)
-                .ok_or_else(|| Error::MissingObjectKey(value, key.clone()))?;
+            .ok_or_else(|| vec![Error::new(value, ErrorKind::InvalidType(JsonType::Object))])?;
 
-            validator.validate(inner_value)?;
+        let mut errors = vec![];
+        for (key, entry) in &self.entries {
+            match (entry, object.get(key)) {
+                (
+                    ObjectEntry::Required(validator) | ObjectEntry::Optional(validator),
+                    Some(inner_value),
+                ) => {
+                    if let Err(inner_errors) = validator.validate_all(inner_value) {
+                        errors.extend(inner_errors.into_iter().map(|error| error.at_key(key)));
+                    }
+                }
+                (ObjectEntry::Required(_), None) => {
+                    errors.push(Error::new(value, ErrorKind::MissingObjectKey(key.clone())));
+                }
+                (ObjectEntry::Absent, Some(inner_value)) => {
+                    errors.push(
+                        Error::new(inner_value, ErrorKind::UnexpectedObjectKey(key.clone()))
+                            .at_key(key),
+                    );
+                }
+                (ObjectEntry::Optional(_) | ObjectEntry::Absent, None) => {}
+            }
         }
 
         if self.strict {
             // Make sure there is no other keys than the one defined in the validator
             // if we are in strict mode.
-            for (key, value) in object {
-                self.key_validators
-                    .get(key)
-                    .ok_or_else(|| Error::UnexpectedObjectKey(value, key.clone()))
-                    .map(|_| ())?;
-            }
+            errors.extend(
+                object
+                    .iter()
+                    .filter(|(key, _)| !self.entries.iter().any(|(entry_key, _)| entry_key == *key))
+                    .map(|(key, value)| {
+                        Error::new(value, ErrorKind::UnexpectedObjectKey(key.clone())).at_key(key)
+                    }),
+            );
         }
 
-        Ok(())
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 }
 
@@ -70,7 +151,8 @@ impl Validator for ObjectValidator {
 mod tests {
     use std::collections::HashMap;
 
-    use crate::{validators, Error, Validator};
+    use super::ObjectEntry;
+    use crate::{validators, ErrorKind, Validator};
 
     #[test]
     fn valid() {
@@ -88,6 +170,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn all_errors() {
+        let mut key_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
+        key_validators.insert(String::from("key"), Box::new(validators::null()));
+        key_validators.insert(String::from("missing"), Box::new(validators::any()));
+
+        let validator = super::object_strict(key_validators);
+        let value = serde_json::json!({"key": 5, "unexpected": null});
+        let errors = validator.validate_all(&value).unwrap_err();
+        assert_eq!(3, errors.len());
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error.kind(), ErrorKind::InvalidType(_))));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error.kind(), ErrorKind::MissingObjectKey(_))));
+        assert!(errors
+            .iter()
+            .any(|error| matches!(error.kind(), ErrorKind::UnexpectedObjectKey(_))));
+    }
+
+    #[test]
+    fn error_path() {
+        let mut inner_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
+        inner_validators.insert(String::from("a/b"), Box::new(validators::null()));
+        let mut key_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
+        key_validators.insert(
+            String::from("key"),
+            Box::new(super::object(inner_validators)),
+        );
+
+        let validator = super::object(key_validators);
+        let value = serde_json::json!({"key": {"a/b": 5}});
+        let error = validator.validate(&value).unwrap_err();
+        assert_eq!("/key/a~1b", error.path().to_string());
+        assert_eq!(&serde_json::json!(5), error.location());
+    }
+
+    #[test]
+    fn optional_entry() {
+        let mut entries = HashMap::new();
+        entries.insert(
+            String::from("key"),
+            ObjectEntry::Optional(Box::new(validators::null())),
+        );
+
+        let validator = super::object_entries(entries);
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!({})));
+        assert_eq!(
+            Ok(()),
+            validator.validate(&serde_json::json!({"key": null}))
+        );
+        assert!(matches!(
+            validator
+                .validate(&serde_json::json!({"key": 5}))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidType(_),
+        ));
+    }
+
+    #[test]
+    fn absent_entry() {
+        let mut entries = HashMap::new();
+        entries.insert(String::from("password"), ObjectEntry::Absent);
+
+        let validator = super::object_entries(entries);
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!({"key": 5})));
+        assert!(matches!(
+            validator
+                .validate(&serde_json::json!({"password": "secret"}))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnexpectedObjectKey(_),
+        ));
+    }
+
+    #[test]
+    fn strict_entries() {
+        let mut entries = HashMap::new();
+        entries.insert(
+            String::from("key"),
+            ObjectEntry::Optional(Box::new(validators::any())),
+        );
+
+        let validator = super::object_entries_strict(entries);
+        assert_eq!(Ok(()), validator.validate(&serde_json::json!({"key": 5})));
+        assert_eq!(
+            "/other",
+            validator
+                .validate(&serde_json::json!({"other": 5}))
+                .unwrap_err()
+                .path()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn errors_in_key_order() {
+        let mut key_validators: Vec<(String, Box<dyn Validator>)> = vec![];
+        for key in ["c", "a", "d", "b"] {
+            key_validators.push((String::from(key), Box::new(validators::null())));
+        }
+
+        let validator = super::object(key_validators);
+        let value = serde_json::json!({"a": 1, "b": 2, "c": 3, "d": 4});
+        let paths: Vec<_> = validator
+            .validate_all(&value)
+            .unwrap_err()
+            .iter()
+            .map(|error| error.path().to_string())
+            .collect();
+        assert_eq!(vec!["/c", "/a", "/d", "/b"], paths);
+    }
+
     #[test]
     fn missing_key() {
         let mut key_validators: HashMap<String, Box<dyn Validator>> = HashMap::new();
@@ -98,8 +295,11 @@ mod tests {
 
         let validator = super::object(key_validators);
         assert!(matches!(
-            validator.validate(&serde_json::json!({})),
-            Err(Error::MissingObjectKey(_, _))
+            validator
+                .validate(&serde_json::json!({}))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::MissingObjectKey(_),
         ));
     }
 }
@@ -0,0 +1,156 @@
+use regex::Regex;
+
+use crate::{collect_errors, Error, ErrorKind, JsonType, Validator, Value};
+
+/// Match a string matching the regular expression `pattern`.
+///
+/// Only available with the `regex` feature.
+///
+/// # Panics
+///
+/// Panics if `pattern` is not a valid regular expression. Use
+/// [`string_matches`] with an already compiled [`Regex`] to handle the error.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{ "name": "charlesvdv" }"#, {
+///     "name": validators::matches(r"^[a-z]+$"),
+/// });
+/// ```
+#[must_use]
+pub fn matches(pattern: &str) -> impl Validator {
+    let regex = Regex::new(pattern)
+        .unwrap_or_else(|error| panic!("invalid regular expression /{pattern}/: {error}"));
+    string_matches(regex)
+}
+
+/// Match a string matching `regex`.
+///
+/// Only available with the `regex` feature.
+#[must_use]
+pub fn string_matches(regex: Regex) -> impl Validator {
+    StringMatchesValidator { regex }
+}
+
+struct StringMatchesValidator {
+    regex: Regex,
+}
+
+impl Validator for StringMatchesValidator {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let string = value
+            .as_str()
+            .ok_or_else(|| Error::new(value, ErrorKind::InvalidType(JsonType::String)))?;
+
+        if self.regex.is_match(string) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                value,
+                ErrorKind::InvalidValue(format!("a string matching /{}/", self.regex)),
+            ))
+        }
+    }
+}
+
+/// Match an object whose keys all match `regex` and whose values all match
+/// `validator`.
+///
+/// Only available with the `regex` feature.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+/// use regex::Regex;
+///
+/// assert_json!(r#"{ "id_1": 1, "id_2": 2 }"#,
+///     validators::keys_match(Regex::new(r"^id_\d+$").unwrap(), validators::ge(1))
+/// );
+/// ```
+#[must_use]
+pub fn keys_match(regex: Regex, validator: impl Validator) -> impl Validator {
+    KeysMatchValidator { regex, validator }
+}
+
+struct KeysMatchValidator<T> {
+    regex: Regex,
+    validator: T,
+}
+
+impl<T> Validator for KeysMatchValidator<T>
+where
+    T: Validator,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_all(value)
+            .map_err(|errors| errors.into_iter().next().unwrap())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| vec![Error::new(value, ErrorKind::InvalidType(JsonType::Object))])?;
+
+        collect_errors(object.iter().map(|(key, inner_value)| {
+            if !self.regex.is_match(key) {
+                let kind = ErrorKind::InvalidObjectKey {
+                    key: key.clone(),
+                    expected: format!("a key matching /{}/", self.regex),
+                };
+                return Err(vec![Error::new(inner_value, kind).at_key(key)]);
+            }
+            self.validator
+                .validate_all(inner_value)
+                .map_err(|errors| errors.into_iter().map(|error| error.at_key(key)).collect())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+    use serde_json::json;
+
+    use crate::{validators, ErrorKind, Validator};
+
+    #[test]
+    fn matches() {
+        let validator = super::matches(r"^[a-z]+$");
+
+        assert_eq!(Ok(()), validator.validate(&json!("abc")));
+        assert_eq!(
+            r#"Invalid value. Expected a string matching /^[a-z]+$/ but got "ABC"."#,
+            validator.validate(&json!("ABC")).unwrap_err().to_string()
+        );
+        assert!(matches!(
+            validator.validate(&json!(5)).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regular expression /[a-z/")]
+    fn matches_invalid_pattern() {
+        let _unused = super::matches("[a-z");
+    }
+
+    #[test]
+    fn keys_match() {
+        let validator = super::keys_match(Regex::new(r"^id_\d+$").unwrap(), validators::null());
+
+        assert_eq!(Ok(()), validator.validate(&json!({})));
+        assert_eq!(
+            Ok(()),
+            validator.validate(&json!({"id_1": null, "id_2": null}))
+        );
+
+        let value = json!({"id_1": 5, "name": null});
+        let errors = validator.validate_all(&value).unwrap_err();
+        assert_eq!(2, errors.len());
+        assert_eq!("/id_1", errors[0].path().to_string());
+        assert_eq!(
+            "/name: Invalid key 'name'. Expected a key matching /^id_\\d+$/.",
+            errors[1].to_string()
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Error, Validator, Value};
+use super::number::as_i128;
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
 
 /// Match if string match predicate.
 pub fn string<F>(predicate: F) -> impl Validator
@@ -6,7 +7,8 @@ where
     F: Fn(&String) -> Result<(), String> + 'static,
 {
     PrimitiveValidator {
-        typename: String::from("string"),
+        json_type: JsonType::String,
+        representation: "a string",
         extract: |val| val.as_str().map(String::from),
         predicate,
     }
@@ -16,7 +18,8 @@ where
 #[must_use]
 pub fn null() -> impl Validator {
     PrimitiveValidator {
-        typename: String::from("null"),
+        json_type: JsonType::Null,
+        representation: "null",
         extract: serde_json::Value::as_null,
         predicate: |()| Ok(()),
     }
@@ -28,32 +31,39 @@ where
     F: Fn(&bool) -> Result<(), String> + 'static,
 {
     PrimitiveValidator {
-        typename: String::from("bool"),
+        json_type: JsonType::Bool,
+        representation: "a bool",
         extract: serde_json::Value::as_bool,
         predicate,
     }
 }
 
 /// Match if number match predicate.
+///
+/// Integral floats like `5.0` are accepted.
 pub fn i64<F>(predicate: F) -> impl Validator
 where
     F: Fn(&i64) -> Result<(), String> + 'static,
 {
     PrimitiveValidator {
-        typename: String::from("i64"),
-        extract: serde_json::Value::as_i64,
+        json_type: JsonType::Number,
+        representation: "an integer representable as i64",
+        extract: |val| as_integer(val).and_then(|int| i64::try_from(int).ok()),
         predicate,
     }
 }
 
 /// Match if number match predicate.
+///
+/// Integral floats like `5.0` are accepted.
 pub fn u64<F>(predicate: F) -> impl Validator
 where
     F: Fn(&u64) -> Result<(), String> + 'static,
 {
     PrimitiveValidator {
-        typename: String::from("u64"),
-        extract: serde_json::Value::as_u64,
+        json_type: JsonType::Number,
+        representation: "an integer representable as u64",
+        extract: |val| as_integer(val).and_then(|int| u64::try_from(int).ok()),
         predicate,
     }
 }
@@ -64,20 +74,30 @@ where
     F: Fn(&f64) -> Result<(), String> + 'static,
 {
     PrimitiveValidator {
-        typename: String::from("f64"),
+        json_type: JsonType::Number,
+        representation: "a number representable as f64",
         extract: serde_json::Value::as_f64,
         predicate,
     }
 }
 
-struct PrimitiveValidator<T, F, G>
+fn as_integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::Number(number) => as_i128(number),
+        _ => None,
+    }
+}
+
+pub(super) struct PrimitiveValidator<T, F, G>
 where
     F: Fn(&T) -> Result<(), String>,
     G: Fn(&Value) -> Option<T>,
 {
-    typename: String,
-    extract: G,
-    predicate: F,
+    pub(super) json_type: JsonType,
+    /// Expected value when the value has the right type but can't be extracted.
+    pub(super) representation: &'static str,
+    pub(super) extract: G,
+    pub(super) predicate: F,
 }
 
 impl<T, F, G> Validator for PrimitiveValidator<T, F, G>
@@ -86,16 +106,23 @@ where
     G: Fn(&Value) -> Option<T>,
 {
     fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
-        let val = (self.extract)(value)
-            .ok_or_else(|| Error::InvalidType(value, self.typename.clone()))?;
-
-        (self.predicate)(&val).map_err(|msg| Error::InvalidValue(value, msg))
+        if JsonType::of(value) != self.json_type {
+            return Err(Error::new(value, ErrorKind::InvalidType(self.json_type)));
+        }
+        let val = (self.extract)(value).ok_or_else(|| {
+            Error::new(
+                value,
+                ErrorKind::InvalidValue(String::from(self.representation)),
+            )
+        })?;
+
+        (self.predicate)(&val).map_err(|msg| Error::new(value, ErrorKind::InvalidValue(msg)))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Error, Validator, Value};
+    use crate::{ErrorKind, JsonType, Validator, Value};
 
     #[test]
     fn string() {
@@ -109,8 +136,11 @@ mod tests {
         let validator = super::string(|_| Err(String::from("error message")));
 
         assert!(matches!(
-            validator.validate(&Value::String(String::new())),
-            Err(Error::InvalidValue(_, _))
+            validator
+                .validate(&Value::String(String::new()))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidValue(_),
         ));
     }
 
@@ -119,8 +149,8 @@ mod tests {
         let validator = super::string(|_| Ok(()));
 
         assert!(matches!(
-            validator.validate(&Value::Null),
-            Err(Error::InvalidType(_, _))
+            validator.validate(&Value::Null).unwrap_err().kind(),
+            ErrorKind::InvalidType(_),
         ));
     }
 
@@ -137,4 +167,38 @@ mod tests {
 
         assert_eq!(Ok(()), validator.validate(&serde_json::json!(4)));
     }
+
+    #[test]
+    fn integral_float() {
+        assert_eq!(
+            Ok(()),
+            super::i64(|&v| if v == -4 { Ok(()) } else { Err(String::new()) })
+                .validate(&serde_json::json!(-4.0))
+        );
+        assert_eq!(
+            Ok(()),
+            super::u64(|&v| if v == 4 { Ok(()) } else { Err(String::new()) })
+                .validate(&serde_json::json!(4.0))
+        );
+    }
+
+    #[test]
+    fn u64_invalid_number() {
+        let validator = super::u64(|_| Ok(()));
+
+        let value = serde_json::json!(-4);
+        let error = validator.validate(&value).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::InvalidValue(_)));
+        assert_eq!(
+            "Invalid value. Expected an integer representable as u64 but got -4.",
+            error.to_string()
+        );
+        assert_eq!(
+            &ErrorKind::InvalidType(JsonType::Number),
+            validator
+                .validate(&serde_json::json!("4"))
+                .unwrap_err()
+                .kind()
+        );
+    }
 }
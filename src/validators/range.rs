@@ -0,0 +1,249 @@
+use core::fmt::Debug;
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+use serde_json::Number;
+
+use super::number::as_i128;
+use crate::{Error, ErrorKind, JsonType, Validator, Value};
+
+/// Match a number inside `range`.
+///
+/// Numbers are compared by mathematical value, whatever the type of the range
+/// bounds. Ranges can also be used directly in [`assert_json`!](crate::assert_json).
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{ "age": 26 }"#, { "age": validators::in_range(18..=99) });
+/// assert_json!(r#"{ "age": 26 }"#, { "age": 18..=99 });
+/// ```
+pub fn in_range<T, R>(range: R) -> impl Validator
+where
+    T: NumberBound + 'static,
+    R: RangeBounds<T> + Debug,
+{
+    RangeValidator {
+        start: range.start_bound().cloned(),
+        end: range.end_bound().cloned(),
+        description: format!("value in {range:?}"),
+    }
+}
+
+/// Match a number greater than `min`.
+pub fn gt<T>(min: T) -> impl Validator
+where
+    T: NumberBound + 'static,
+{
+    RangeValidator {
+        start: Bound::Excluded(min),
+        end: Bound::Unbounded,
+        description: format!("value > {min:?}"),
+    }
+}
+
+/// Match a number greater than or equal to `min`.
+pub fn ge<T>(min: T) -> impl Validator
+where
+    T: NumberBound + 'static,
+{
+    RangeValidator {
+        start: Bound::Included(min),
+        end: Bound::Unbounded,
+        description: format!("value >= {min:?}"),
+    }
+}
+
+/// Match a number lower than `max`.
+pub fn lt<T>(max: T) -> impl Validator
+where
+    T: NumberBound + 'static,
+{
+    RangeValidator {
+        start: Bound::Unbounded,
+        end: Bound::Excluded(max),
+        description: format!("value < {max:?}"),
+    }
+}
+
+/// Match a number lower than or equal to `max`.
+pub fn le<T>(max: T) -> impl Validator
+where
+    T: NumberBound + 'static,
+{
+    RangeValidator {
+        start: Bound::Unbounded,
+        end: Bound::Included(max),
+        description: format!("value <= {max:?}"),
+    }
+}
+
+/// Match a number between `min` and `max`, both included.
+pub fn between<T>(min: T, max: T) -> impl Validator
+where
+    T: NumberBound + 'static,
+{
+    in_range(min..=max)
+}
+
+/// Numeric type usable as a bound of the range validators, like [`in_range`].
+pub trait NumberBound: Copy + Debug {
+    /// Compare a JSON number with the bound.
+    ///
+    /// Returns `None` if the number can't be compared, like a NaN bound.
+    fn cmp_number(&self, number: &Number) -> Option<Ordering>;
+}
+
+macro_rules! impl_integer_bound {
+    ($($ty:ty),*) => {
+        $(
+            impl NumberBound for $ty {
+                fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+                    cmp_integer(i128::try_from(*self).ok()?, number)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+fn cmp_integer(bound: i128, number: &Number) -> Option<Ordering> {
+    match as_i128(number) {
+        Some(int) => Some(int.cmp(&bound)),
+        // The number is not an integer, the precision of the bound as f64 is
+        // enough to order them.
+        #[expect(clippy::cast_precision_loss, reason = "no exact bound is needed")]
+        None => number.as_f64()?.partial_cmp(&(bound as f64)),
+    }
+}
+
+impl NumberBound for f64 {
+    fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+        number.as_f64()?.partial_cmp(self)
+    }
+}
+
+impl NumberBound for f32 {
+    fn cmp_number(&self, number: &Number) -> Option<Ordering> {
+        // Widening the binary value would turn `0.1` into `0.10000000149`, use
+        // the decimal value written in the code instead.
+        let bound: f64 = self.to_string().parse().ok()?;
+        bound.cmp_number(number)
+    }
+}
+
+struct RangeValidator<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+    description: String,
+}
+
+impl<T> RangeValidator<T>
+where
+    T: NumberBound,
+{
+    fn contains(&self, number: &Number) -> bool {
+        let after_start = match &self.start {
+            Bound::Included(start) => start.cmp_number(number).is_some_and(Ordering::is_ge),
+            Bound::Excluded(start) => start.cmp_number(number).is_some_and(Ordering::is_gt),
+            Bound::Unbounded => true,
+        };
+        let before_end = match &self.end {
+            Bound::Included(end) => end.cmp_number(number).is_some_and(Ordering::is_le),
+            Bound::Excluded(end) => end.cmp_number(number).is_some_and(Ordering::is_lt),
+            Bound::Unbounded => true,
+        };
+        after_start && before_end
+    }
+}
+
+impl<T> Validator for RangeValidator<T>
+where
+    T: NumberBound,
+{
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        let Value::Number(number) = value else {
+            return Err(Error::new(value, ErrorKind::InvalidType(JsonType::Number)));
+        };
+
+        if self.contains(number) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                value,
+                ErrorKind::InvalidValue(self.description.clone()),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ErrorKind, Validator};
+
+    #[test]
+    fn in_range() {
+        let validator = super::in_range(18..=99);
+
+        assert_eq!(Ok(()), validator.validate(&json!(18)));
+        assert_eq!(Ok(()), validator.validate(&json!(99.0)));
+        assert!(validator.validate(&json!(99.5)).is_err());
+        assert_eq!(
+            "Invalid value. Expected value in 18..=99 but got 7.",
+            validator.validate(&json!(7)).unwrap_err().to_string()
+        );
+        assert!(matches!(
+            validator.validate(&json!("18")).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn exclusive_range() {
+        let validator = super::in_range(0.0..1.0);
+
+        assert_eq!(Ok(()), validator.validate(&json!(0)));
+        assert_eq!(Ok(()), validator.validate(&json!(0.5)));
+        assert!(validator.validate(&json!(1)).is_err());
+    }
+
+    #[test]
+    fn unsigned_bound() {
+        let validator = super::in_range(..10_u64);
+
+        assert_eq!(Ok(()), validator.validate(&json!(-5)));
+        assert_eq!(Ok(()), validator.validate(&json!(9.5)));
+        assert!(validator.validate(&json!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(Ok(()), super::gt(5).validate(&json!(5.5)));
+        assert!(super::gt(5).validate(&json!(5)).is_err());
+        assert_eq!(Ok(()), super::ge(5).validate(&json!(5)));
+        assert_eq!(Ok(()), super::lt(5).validate(&json!(4)));
+        assert!(super::lt(5).validate(&json!(5.0)).is_err());
+        assert_eq!(Ok(()), super::le(5.5).validate(&json!(5.5)));
+        assert_eq!(Ok(()), super::between(-1, 1).validate(&json!(1)));
+        assert_eq!(
+            "Invalid value. Expected value >= 5 but got 4.",
+            super::ge(5).validate(&json!(4)).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn f32_bound() {
+        assert_eq!(Ok(()), super::in_range(0.1_f32..).validate(&json!(0.1)));
+        assert_eq!(Ok(()), super::le(0.3_f32).validate(&json!(0.3)));
+        assert!(super::lt(0.1_f32).validate(&json!(0.1)).is_err());
+        assert!(super::le(f32::NAN).validate(&json!(1)).is_err());
+    }
+
+    #[test]
+    fn nan_bound() {
+        assert!(super::le(f64::NAN).validate(&json!(1)).is_err());
+    }
+}
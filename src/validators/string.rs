@@ -0,0 +1,280 @@
+use core::fmt;
+
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{collect_errors, Error, ErrorKind, JsonType, Validator, Value};
+
+/// Match a string passing all the checks added to the returned [`Str`].
+///
+/// Lengths are counted in characters (Unicode scalar values), not bytes.
+///
+/// ```
+/// use assert_json::{assert_json, validators};
+///
+/// assert_json!(r#"{ "id": "usr_charlesvdv" }"#, {
+///     "id": validators::str().min_len(3).max_len(64).starts_with("usr_"),
+/// });
+/// ```
+#[must_use]
+pub fn str() -> Str {
+    Str::default()
+}
+
+/// Validator created by [`str`].
+#[derive(Debug, Clone, Default)]
+pub struct Str {
+    checks: Vec<StrCheck>,
+}
+
+#[derive(Debug, Clone)]
+enum StrCheck {
+    MinLen(usize),
+    MaxLen(usize),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    Eq(String, StringComparison),
+}
+
+impl Str {
+    /// Accept strings of at least `min` characters.
+    #[must_use]
+    pub fn min_len(self, min: usize) -> Str {
+        self.check(StrCheck::MinLen(min))
+    }
+
+    /// Accept strings of at most `max` characters.
+    #[must_use]
+    pub fn max_len(self, max: usize) -> Str {
+        self.check(StrCheck::MaxLen(max))
+    }
+
+    /// Accept strings starting with `prefix`.
+    #[must_use]
+    pub fn starts_with(self, prefix: impl Into<String>) -> Str {
+        self.check(StrCheck::StartsWith(prefix.into()))
+    }
+
+    /// Accept strings ending with `suffix`.
+    #[must_use]
+    pub fn ends_with(self, suffix: impl Into<String>) -> Str {
+        self.check(StrCheck::EndsWith(suffix.into()))
+    }
+
+    /// Accept strings containing `pattern`.
+    #[must_use]
+    pub fn contains(self, pattern: impl Into<String>) -> Str {
+        self.check(StrCheck::Contains(pattern.into()))
+    }
+
+    /// Accept strings equal to `expected` ignoring case.
+    #[must_use]
+    pub fn eq_ignore_case(self, expected: impl Into<String>) -> Str {
+        self.eq_with(expected, StringComparison::IgnoreCase)
+    }
+
+    /// Accept strings equal to `expected` once leading and trailing whitespace
+    /// is removed and inner whitespace is collapsed to a single space.
+    #[must_use]
+    pub fn eq_normalized_whitespace(self, expected: impl Into<String>) -> Str {
+        self.eq_with(expected, StringComparison::NormalizedWhitespace)
+    }
+
+    /// Accept strings equal to `expected` once both are in Unicode
+    /// Normalization Form C, so `"é"` matches `"e\u{301}"`.
+    ///
+    /// Only available with the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    #[must_use]
+    pub fn eq_nfc(self, expected: impl Into<String>) -> Str {
+        self.eq_with(expected, StringComparison::Nfc)
+    }
+
+    /// Accept strings equal to `expected` according to `comparison`.
+    #[must_use]
+    pub fn eq_with(self, expected: impl Into<String>, comparison: StringComparison) -> Str {
+        self.check(StrCheck::Eq(expected.into(), comparison))
+    }
+
+    fn check(mut self, check: StrCheck) -> Str {
+        self.checks.push(check);
+        self
+    }
+}
+
+impl Validator for Str {
+    fn validate<'a>(&self, value: &'a Value) -> Result<(), Error<'a>> {
+        self.validate_all(value)
+            .map_err(|errors| errors.into_iter().next().unwrap())
+    }
+
+    fn validate_all<'a>(&self, value: &'a Value) -> Result<(), Vec<Error<'a>>> {
+        let string = value
+            .as_str()
+            .ok_or_else(|| vec![Error::new(value, ErrorKind::InvalidType(JsonType::String))])?;
+
+        collect_errors(self.checks.iter().map(|check| {
+            check
+                .run(string)
+                .map_err(|kind| vec![Error::new(value, kind)])
+        }))
+    }
+}
+
+impl StrCheck {
+    fn run(&self, string: &str) -> Result<(), ErrorKind> {
+        let invalid_value = |description: String| Err(ErrorKind::InvalidValue(description));
+        match self {
+            StrCheck::MinLen(min) if string.chars().count() < *min => {
+                invalid_length(string, format!("at least {min} characters"))
+            }
+            StrCheck::MaxLen(max) if string.chars().count() > *max => {
+                invalid_length(string, format!("at most {max} characters"))
+            }
+            StrCheck::StartsWith(prefix) if !string.starts_with(prefix.as_str()) => invalid_value(
+                format!("a string starting with {}", Value::from(prefix.as_str())),
+            ),
+            StrCheck::EndsWith(suffix) if !string.ends_with(suffix.as_str()) => invalid_value(
+                format!("a string ending with {}", Value::from(suffix.as_str())),
+            ),
+            StrCheck::Contains(pattern) if !string.contains(pattern.as_str()) => invalid_value(
+                format!("a string containing {}", Value::from(pattern.as_str())),
+            ),
+            StrCheck::Eq(expected, comparison)
+                if comparison.normalize(expected) != comparison.normalize(string) =>
+            {
+                Err(ErrorKind::StringNotEqual {
+                    expected: expected.clone(),
+                    comparison: *comparison,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn invalid_length(string: &str, expected: String) -> Result<(), ErrorKind> {
+    Err(ErrorKind::InvalidLength {
+        expected,
+        actual: string.chars().count(),
+    })
+}
+
+/// How [`Str::eq_with`] compares strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StringComparison {
+    /// Compare the lowercase form of the strings.
+    IgnoreCase,
+    /// Compare the strings with leading and trailing whitespace removed and
+    /// inner whitespace collapsed to a single space.
+    NormalizedWhitespace,
+    /// Compare the Unicode Normalization Form C of the strings.
+    ///
+    /// Only available with the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    Nfc,
+}
+
+impl StringComparison {
+    /// The form of `string` compared by this comparison.
+    pub(crate) fn normalize(self, string: &str) -> String {
+        match self {
+            StringComparison::IgnoreCase => string.to_lowercase(),
+            StringComparison::NormalizedWhitespace => {
+                string.split_whitespace().collect::<Vec<_>>().join(" ")
+            }
+            #[cfg(feature = "unicode-normalization")]
+            StringComparison::Nfc => string.nfc().collect(),
+        }
+    }
+}
+
+impl fmt::Display for StringComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StringComparison::IgnoreCase => "ignoring case",
+            StringComparison::NormalizedWhitespace => "with normalized whitespace",
+            #[cfg(feature = "unicode-normalization")]
+            StringComparison::Nfc => "in NFC",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ErrorKind, Validator};
+
+    #[test]
+    fn len() {
+        let validator = super::str().min_len(3).max_len(5);
+
+        assert_eq!(Ok(()), validator.validate(&json!("abc")));
+        assert_eq!(Ok(()), validator.validate(&json!("ééééé")));
+        assert_eq!(
+            "Invalid length. Expected at least 3 characters but got 2.",
+            validator.validate(&json!("ab")).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Invalid length. Expected at most 5 characters but got 6.",
+            validator
+                .validate(&json!("abcdef"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(matches!(
+            validator.validate(&json!(5)).unwrap_err().kind(),
+            ErrorKind::InvalidType(_)
+        ));
+    }
+
+    #[test]
+    fn affixes() {
+        let validator = super::str()
+            .starts_with("usr_")
+            .ends_with("!")
+            .contains("@");
+
+        assert_eq!(Ok(()), validator.validate(&json!("usr_a@b!")));
+        assert_eq!(
+            r#"Invalid value. Expected a string starting with "usr_" but got "a@b!"."#,
+            validator.validate(&json!("a@b!")).unwrap_err().to_string()
+        );
+        assert_eq!(3, validator.validate_all(&json!("abc")).unwrap_err().len());
+    }
+
+    #[test]
+    fn eq_ignore_case() {
+        let validator = super::str().eq_ignore_case("OK");
+
+        assert_eq!(Ok(()), validator.validate(&json!("ok")));
+        assert_eq!(Ok(()), validator.validate(&json!("Ok")));
+        assert_eq!(
+            r#"Invalid value. Expected "OK" ignoring case but got "KO"."#,
+            validator.validate(&json!("KO")).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn eq_normalized_whitespace() {
+        let validator = super::str().eq_normalized_whitespace("hello world");
+
+        assert_eq!(Ok(()), validator.validate(&json!("  hello \n\t world ")));
+        assert!(matches!(
+            validator.validate(&json!("helloworld")).unwrap_err().kind(),
+            ErrorKind::StringNotEqual { .. }
+        ));
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn eq_nfc() {
+        let validator = super::str().eq_nfc("caf\u{e9}");
+
+        assert_eq!(Ok(()), validator.validate(&json!("cafe\u{301}")));
+        assert!(validator.validate(&json!("cafe")).is_err());
+    }
+}
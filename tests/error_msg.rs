@@ -1,7 +1,7 @@
 use std::any::Any;
 use std::io::IsTerminal as _;
 
-use assert_json::{assert_json, validators};
+use assert_json::{assert_json, json_validator, validators};
 use indoc::indoc;
 
 macro_rules! assert_panic_output {
@@ -43,10 +43,10 @@ fn primitive_invalid_type() {
 #[test]
 fn missing_object_key() {
     let expected_output = indoc! {r#"
-        1 │ ╭ {
-        2 │ │     "key": "val"
-        3 │ │ }
-          │ ╰─^ Missing key 'missing_key' in object
+        1 │ { "key": "val" }
+          │                ^ Missing key 'missing_key' in object
+          │
+          = keys present in the object: 'key'
     "#};
 
     assert_panic_output!(
@@ -58,13 +58,48 @@ fn missing_object_key() {
     );
 }
 
+#[test]
+fn unexpected_object_key() {
+    let expected_output = indoc! {r#"
+        1 │ { "key": "val", "other": 5 }
+          │                 ^^^^^^^ /other: Key 'other' is not expected in object
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{ "key": "val", "other": 5 }"#, strict {
+            "key": "val",
+        })
+    );
+}
+
+#[test]
+fn missing_nested_object_key() {
+    let expected_output = indoc! {r"
+        4 │     }
+          │     ^ /result: Missing key 'name' in object
+          │
+          = /result has no keys
+    "};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(serde_json::json!({ "result": {} }), {
+            "result": {
+                "name": "charlesvdv",
+            },
+        })
+    );
+}
+
 #[test]
 fn test_readme_example() {
     // If the error is updated, don't forget to update the README!
     let expected_output = indoc! {r#"
+          ┌─ :6:25
           │
-        4 │         "name": "incorrect name"
-          │                 ^^^^^^^^^^^^^^^^ Invalid value. Expected "charlesvdv" but got "incorrect name".
+        6 │                 "name": "incorrect name"
+          │                         ^^^^^^^^^^^^^^^^ /result/name: Invalid value. Expected "charlesvdv" but got "incorrect name".
     "#};
     let json = r#"
         {
@@ -87,3 +122,230 @@ fn test_readme_example() {
         )
     );
 }
+
+#[test]
+fn serde_value_input() {
+    let expected_output = indoc! {r#"
+          │
+        2 │     "key": "val"
+          │            ^^^^^ /key: Invalid value. Expected "other" but got "val".
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(serde_json::json!({ "key": "val" }), {
+            "key": "other",
+        })
+    );
+}
+
+#[test]
+fn serde_value_input_escaped_string() {
+    let expected_output = indoc! {r#"
+          │
+        3 │     "name": "x"
+          │             ^^^ /name: Invalid value. Expected "y" but got "x".
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(serde_json::json!({ "description": "two\nlines \"quoted\"", "name": "x" }), {
+            "name": "y",
+        })
+    );
+}
+
+#[test]
+fn multiple_errors() {
+    let expected_output = indoc! {r#"
+        error: Invalid JSON (2 errors)
+          ┌─ :2:12
+          │
+        2 │     "age": "26",
+          │            ^^^^ /age: Invalid type. Expected number but got string.
+        3 │     "name": "incorrect name"
+          │             ^^^^^^^^^^^^^^^^ /name: Invalid value. Expected "charlesvdv" but got "incorrect name".
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{
+    "age": "26",
+    "name": "incorrect name"
+}"#, {
+            "age": 26,
+            "name": "charlesvdv",
+        })
+    );
+}
+
+#[test]
+fn none_matched_causes() {
+    let expected_output = indoc! {r#"
+        1 │ { "id": 5 }
+          │         ^ /id: None of the 2 alternatives matched
+          │
+          = /id: None of the 2 alternatives matched:
+              - /id: Invalid type. Expected null but got number.
+              - /id: Invalid type. Expected string but got number.
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{ "id": 5 }"#, {
+            "id": validators::any_of(vec![
+                Box::new(validators::null()),
+                Box::new(validators::string(|_| Ok(()))),
+            ]),
+        })
+    );
+}
+
+#[test]
+fn long_string_diff() {
+    let expected_output = indoc! {r#"
+        1 │ { "title": "The Quick Brown Fox Jumps Over The Lazy Dog" }
+          │            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ /title: Invalid value. Expected "the quick brown fox jumped over the lazy dog" ignoring case but got "The Quick Brown Fox Jumps Over The Lazy Dog".
+          │
+          = difference ignoring case ([-expected-] {+actual+}): …n fox jump[-ed-]{+s+} over the …
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{ "title": "The Quick Brown Fox Jumps Over The Lazy Dog" }"#, {
+            "title": validators::str()
+                .eq_ignore_case("the quick brown fox jumped over the lazy dog"),
+        })
+    );
+}
+
+#[test]
+fn embedded_json() {
+    let expected_output = indoc! {r#"
+        1 │ { "payload": "{\"id\": 2}" }
+          │              ^^^^^^^^^^^^^ /payload: Invalid embedded JSON. #/id: Invalid value. Expected 1 but got 2.
+          │
+          = embedded JSON document:
+            error: Invalid JSON
+              ┌─ :1:8
+              │
+            1 │ {"id": 2}
+              │        ^ /payload#/id: Invalid value. Expected 1 but got 2.
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{ "payload": "{\"id\": 2}" }"#, {
+            "payload": validators::json_string(json_validator!({ "id": 1 })),
+        })
+    );
+}
+
+#[test]
+fn jwt_claims() {
+    let expected_output = indoc! {r#"
+        1 │ { "token": "eyJhbGciOiJub25lIn0.eyJzdWIiOiJ1c3JfMSJ9." }
+          │            ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ /token: Invalid embedded JSON. #/claims/sub: Invalid value. Expected "usr_2" but got "usr_1".
+          │
+          = embedded JSON document:
+            error: Invalid JSON
+              ┌─ :3:16
+              │
+            3 │         "sub": "usr_1"
+              │                ^^^^^^^ /token#/claims/sub: Invalid value. Expected "usr_2" but got "usr_1".
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(r#"{ "token": "eyJhbGciOiJub25lIn0.eyJzdWIiOiJ1c3JfMSJ9." }"#, {
+            "token": validators::jwt(
+                json_validator!({ "alg": "none" }),
+                json_validator!({ "sub": "usr_2" }),
+            ),
+        })
+    );
+}
+
+#[test]
+fn invalid_json_trailing_comma() {
+    let expected_output = indoc! {r#"
+        error: Failed to parse JSON (syntax error)
+          ┌─ :3:17
+          │
+        3 │     "name": "ok",
+          │                 ^ trailing comma
+          │
+          = help: JSON doesn't allow trailing commas, remove the last `,`
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!("{\n    \"id\": 1,\n    \"name\": \"ok\",\n}", { "id": 1 })
+    );
+}
+
+#[test]
+fn invalid_json_single_quotes() {
+    let expected_output = indoc! {r#"
+        1 │ { "name": 'ok' }
+          │           ^ expected value
+          │
+          = help: JSON strings must be enclosed in double quotes `"`, not single quotes
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!("{ \"name\": 'ok' }", { "name": "ok" })
+    );
+}
+
+#[test]
+fn invalid_json_unquoted_key() {
+    let expected_output = indoc! {r#"
+        1 │ { name: "ok" }
+          │   ^ key must be a string
+          │
+          = help: JSON object keys must be enclosed in double quotes `"`
+    "#};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!("{ name: \"ok\" }", { "name": "ok" })
+    );
+}
+
+#[test]
+fn unmatched_validator() {
+    let expected_output = indoc! {r"
+        1 │ [3, 1]
+          │ ^^^^^^ No match for expected array element 1. Candidate element 1 is matched by another expected element
+    "};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(
+            "[3, 1]",
+            validators::array_contains(vec![
+                Box::new(validators::eq(1)),
+                Box::new(validators::eq(1)),
+            ])
+        )
+    );
+
+    let expected_output = indoc! {r"
+        1 │ [1, 1, 3]
+          │ ^^^^^^^^^ No match for expected array element 2. Candidate elements 0, 1 are matched by other expected elements
+    "};
+
+    assert_panic_output!(
+        expected_output,
+        assert_json!(
+            "[1, 1, 3]",
+            validators::array_contains(vec![
+                Box::new(validators::eq(1)),
+                Box::new(validators::eq(1)),
+                Box::new(validators::eq(1)),
+            ])
+        )
+    );
+}